@@ -0,0 +1,129 @@
+//! Throughput of `Stripper` on large synthetic documents.
+//!
+//! Run with `cargo bench`; each corpus is a few megabytes so per-call setup
+//! costs disappear in the measurement.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use remoji::{Options, Replacement, Stripper, Syntax};
+
+const TARGET_LEN: usize = 4 << 20;
+
+fn repeat(paragraph: &str) -> String {
+    paragraph.repeat(TARGET_LEN / paragraph.len() + 1)
+}
+
+/// Plain English prose without a single emoji, the common case.
+fn ascii_prose() -> String {
+    repeat(
+        "The quick brown fox jumps over the lazy dog. Pack my box with five dozen \
+         liquor jugs, then ship it before the release goes out on Friday.\n\n",
+    )
+}
+
+/// Non-ASCII prose (accents, CJK, symbols below the emoji ranges) without emojis.
+fn unicode_prose() -> String {
+    repeat("Café crème, naïve façade — 東京の天気は晴れ; Ω ≈ 3.14 × r² ± ε.\n\n")
+}
+
+/// Every other word is an emoji, including ZWJ, skin tone, flag and keycap sequences.
+fn emoji_dense() -> String {
+    repeat(
+        "Ship 🚀 it ✅ now 👍🏽 team 👨‍👩‍👧‍👦 from 🇯🇵 step 1️⃣ done ❤️ \
+         build 🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F} ok\n",
+    )
+}
+
+/// Markdown mixing prose, headings, lists, tables and fenced code.
+fn markdown() -> String {
+    repeat(
+        "# Release 🎉\n\n\
+         Highlights for this *week* 🚀, see [the notes](https://example.com/🚀).\n\n\
+         - Fixed the parser ✅\n- Faster builds ⚡\n\n\
+         | Status | Item |\n|---|---|\n| ✅ | docs |\n\n\
+         ```rust\nlet rocket = \"🚀\"; // kept\n```\n\n\
+         Inline `code 🚀` stays, prose 🔥 goes.\n\n",
+    )
+}
+
+fn corpora() -> [(&'static str, String); 4] {
+    [
+        ("ascii-prose", ascii_prose()),
+        ("unicode-prose", unicode_prose()),
+        ("emoji-dense", emoji_dense()),
+        ("markdown", markdown()),
+    ]
+}
+
+fn bench_strip(c: &mut Criterion) {
+    let configurations = [
+        (
+            "plain",
+            Options {
+                syntax: Syntax::Plain,
+                ..Options::default()
+            },
+        ),
+        ("markdown", Options::default()),
+        (
+            "shortcode",
+            Options {
+                replace: Replacement::Shortcode,
+                ..Options::default()
+            },
+        ),
+    ];
+
+    for (config, options) in configurations {
+        let stripper = Stripper::new(options);
+        let mut group = c.benchmark_group(format!("strip/{}", config));
+        group.sample_size(20);
+        for (name, text) in corpora() {
+            group.throughput(Throughput::Bytes(text.len() as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(name), &text, |b, text| {
+                b.iter(|| stripper.strip(black_box(text)))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Short Markdown paragraphs with one emoji each: as many prose regions as
+/// matches, the shape where per-match lookups turn quadratic.
+fn paragraphs(len: usize) -> String {
+    let paragraph = "Para 🚀 one.\n\n";
+    paragraph.repeat(len / paragraph.len() + 1)
+}
+
+/// The same corpus at growing sizes; throughput should stay flat.
+fn bench_scaling(c: &mut Criterion) {
+    let stripper = Stripper::new(Options::default());
+    let mut group = c.benchmark_group("scaling/markdown-paragraphs");
+    group.sample_size(10);
+    for len in [256 << 10, 1 << 20, TARGET_LEN] {
+        let text = paragraphs(len);
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(format!("{}KiB", len >> 10)), &text, |b, text| {
+            b.iter(|| stripper.strip(black_box(text)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_find(c: &mut Criterion) {
+    let stripper = Stripper::new(Options {
+        syntax: Syntax::Plain,
+        ..Options::default()
+    });
+    let mut group = c.benchmark_group("find");
+    group.sample_size(20);
+    for (name, text) in corpora() {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &text, |b, text| {
+            b.iter(|| stripper.find(black_box(text)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_strip, bench_scaling, bench_find);
+criterion_main!(benches);
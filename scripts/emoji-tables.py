@@ -0,0 +1,124 @@
+#!/usr/bin/env python3
+"""Generate src/emoji/tables.rs from Unicode's emoji-data.txt and
+emoji-test.txt.
+
+Usage, with the files of the Unicode version below:
+
+    curl -O https://www.unicode.org/Public/16.0.0/ucd/emoji/emoji-data.txt
+    curl -O https://www.unicode.org/Public/emoji/16.0/emoji-test.txt
+    python3 scripts/emoji-tables.py emoji-data.txt emoji-test.txt > src/emoji/tables.rs
+
+EMOJI_BASE is the union of the Extended_Pictographic and
+Emoji_Presentation properties, and EMOJI_PRESENTATION the Emoji_Presentation
+property alone, each with overlapping and adjacent ranges merged.
+
+SUBGROUPS lists the CLDR subgroups of emoji-test.txt, and
+SEQUENCE_SUBGROUPS the subgroup of each fully-qualified sequence without
+skin tones, sorted for binary search.
+"""
+
+import sys
+
+UNICODE = "16.0.0"
+PROPERTIES = ("Extended_Pictographic", "Emoji_Presentation")
+# Skin tones and hair styles on their own; never matched.
+SKIPPED_GROUPS = {"Component"}
+MODIFIERS = range(0x1F3FB, 0x1F3FF + 1)
+
+
+def read(path):
+    ranges = {prop: [] for prop in PROPERTIES}
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            data = line.split("#", 1)[0].strip()
+            if not data:
+                continue
+            codepoints, prop = (field.strip() for field in data.split(";"))
+            if prop not in ranges:
+                continue
+            start, _, end = codepoints.partition("..")
+            ranges[prop].append((int(start, 16), int(end or start, 16)))
+    return ranges
+
+
+def read_subgroups(path):
+    subgroups = []
+    sequences = []
+    group = None
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            if line.startswith("# group:"):
+                group = line.split(":", 1)[1].strip()
+            elif line.startswith("# subgroup:") and group not in SKIPPED_GROUPS:
+                subgroups.append(line.split(":", 1)[1].strip())
+            data = line.split("#", 1)[0].strip()
+            if not data or group in SKIPPED_GROUPS:
+                continue
+            codepoints, status = (field.strip() for field in data.split(";"))
+            codepoints = [int(c, 16) for c in codepoints.split()]
+            if status == "fully-qualified" and not any(c in MODIFIERS for c in codepoints):
+                sequences.append((codepoints, len(subgroups) - 1))
+    # Sorted by UTF-8 bytes, the order of Rust's `str`.
+    sequences.sort(key=lambda s: "".join(map(chr, s[0])).encode("utf-8"))
+    return subgroups, sequences
+
+
+def merge(ranges):
+    merged = []
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1][1] = max(merged[-1][1], end)
+        else:
+            merged.append([start, end])
+    return merged
+
+
+def table(doc, name, ranges):
+    print(f"/// {doc}")
+    print(f"pub(super) const {name}: &[(char, char)] = &[")
+    for start, end in merge(ranges):
+        print(f"    ('\\u{{{start:X}}}', '\\u{{{end:X}}}'),")
+    print("];")
+
+
+def main():
+    if len(sys.argv) != 3:
+        sys.exit(f"usage: {sys.argv[0]} <emoji-data.txt> <emoji-test.txt>")
+    ranges = read(sys.argv[1])
+    subgroups, sequences = read_subgroups(sys.argv[2])
+    assert len(subgroups) <= 256, "subgroup indices must fit in a u8"
+    print(f"// Derived from the Unicode {UNICODE} emoji-data.txt and emoji-test.txt.")
+    print("// Generated by scripts/emoji-tables.py.")
+    print()
+    table(
+        "Codepoints that can start an emoji element, as sorted inclusive ranges:\n"
+        "/// the Extended_Pictographic and Emoji_Presentation properties.",
+        "EMOJI_BASE",
+        ranges["Extended_Pictographic"] + ranges["Emoji_Presentation"],
+    )
+    print()
+    table(
+        "Codepoints shown as emojis by default, as sorted inclusive ranges: the\n"
+        "/// Emoji_Presentation property. The others are text unless a VS16, a\n"
+        "/// skin tone or a ZWJ follows them.",
+        "EMOJI_PRESENTATION",
+        ranges["Emoji_Presentation"],
+    )
+    print()
+    print("/// CLDR emoji subgroups, in order.")
+    print("pub(super) const SUBGROUPS: &[&str] = &[")
+    for name in subgroups:
+        print(f'    "{name}",')
+    print("];")
+    print()
+    print("/// Fully-qualified sequences without skin tones, sorted, and the index of")
+    print("/// their subgroup in [`SUBGROUPS`].")
+    print("pub(super) const SEQUENCE_SUBGROUPS: &[(&str, u8)] = &[")
+    for codepoints, subgroup in sequences:
+        sequence = "".join(f"\\u{{{c:X}}}" for c in codepoints)
+        print(f'    ("{sequence}", {subgroup}),')
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
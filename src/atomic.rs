@@ -0,0 +1,243 @@
+//! Crash-safe file replacement.
+//!
+//! New content goes to a temporary file next to the target, is flushed to
+//! disk and then renamed over the original, so readers see either the old or
+//! the new document and never a truncated one.
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use filetime::FileTime;
+use tempfile::{NamedTempFile, TempPath};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WriteOptions {
+    /// Give the new file the modification time of the one it replaces.
+    pub keep_mtime: bool,
+}
+
+/// Atomically replace the contents of `path`.
+///
+/// Symbolic links are resolved and their target is rewritten, so the link
+/// itself survives. The permissions of the original are kept, and on Unix
+/// its owner and group too when the process is allowed to set them.
+pub fn write(path: &Path, contents: &[u8], options: WriteOptions) -> Result<()> {
+    prepare(path, contents, options)?.commit()
+}
+
+/// New contents for a file, already on disk next to it but not yet in place.
+/// Dropping it without committing removes the temporary file.
+pub struct Prepared {
+    temp: TempPath,
+    target: PathBuf,
+    dir: PathBuf,
+}
+
+impl Prepared {
+    /// The file that committing replaces, with symbolic links resolved.
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    /// Rename the new contents over the target.
+    pub fn commit(self) -> Result<()> {
+        let Prepared { temp, target, dir } = self;
+        // The error hands the temporary path back; keeping only the I/O error
+        // drops it, which removes the temporary file right away.
+        temp.persist(&target)
+            .map_err(|e| e.error)
+            .with_context(|| format!("Could not replace `{}`", target.display()))?;
+        sync_dir(&dir);
+        Ok(())
+    }
+}
+
+/// The first half of [`write`]: everything up to the final rename.
+pub fn prepare(path: &Path, contents: &[u8], options: WriteOptions) -> Result<Prepared> {
+    let target = resolve(path)?;
+    let metadata = fs::metadata(&target)
+        .with_context(|| format!("Could not read metadata of `{}`", target.display()))?;
+    let dir = match target.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let mut file = NamedTempFile::new_in(&dir)
+        .with_context(|| format!("Could not create a temporary file in `{}`", dir.display()))?;
+    file.write_all(contents)
+        .and_then(|()| file.as_file().set_permissions(metadata.permissions()))
+        .with_context(|| format!("Could not write a temporary file for `{}`", target.display()))?;
+    keep_owner(file.as_file(), &metadata);
+    if options.keep_mtime {
+        filetime::set_file_handle_times(file.as_file(), None, Some(FileTime::from_last_modification_time(&metadata)))
+            .with_context(|| format!("Could not set the modification time of `{}`", target.display()))?;
+    }
+    file.as_file()
+        .sync_all()
+        .with_context(|| format!("Could not flush `{}` to disk", target.display()))?;
+
+    // Only the path is kept, so many files can be prepared at once without
+    // running out of file descriptors.
+    Ok(Prepared {
+        temp: file.into_temp_path(),
+        target,
+        dir,
+    })
+}
+
+/// `path` with any symbolic links in its final component followed.
+fn resolve(path: &Path) -> Result<PathBuf> {
+    let mut path = path.to_path_buf();
+    // Bounded like the kernel's own limit to avoid spinning on link cycles.
+    for _ in 0..40 {
+        let metadata = fs::symlink_metadata(&path)
+            .with_context(|| format!("Could not read metadata of `{}`", path.display()))?;
+        if !metadata.file_type().is_symlink() {
+            return Ok(path);
+        }
+        let link = fs::read_link(&path)
+            .with_context(|| format!("Could not read link `{}`", path.display()))?;
+        path = match path.parent() {
+            Some(parent) => parent.join(link),
+            None => link,
+        };
+    }
+    anyhow::bail!("Too many levels of symbolic links at `{}`", path.display())
+}
+
+#[cfg(unix)]
+fn keep_owner(file: &fs::File, metadata: &fs::Metadata) {
+    use std::os::unix::fs::MetadataExt;
+
+    // Only root can hand a file to another user; anyone else gets a file they
+    // own, which is what a plain rewrite by that user would produce anyway.
+    let _ = std::os::unix::fs::fchown(file, Some(metadata.uid()), Some(metadata.gid()));
+}
+
+#[cfg(not(unix))]
+fn keep_owner(_file: &fs::File, _metadata: &fs::Metadata) {}
+
+/// Make the rename itself durable; not every platform can open a directory.
+fn sync_dir(dir: &Path) {
+    if let Ok(dir) = fs::File::open(dir) {
+        let _ = dir.sync_all();
+    }
+}
+
+/// Prepared files that are put in place together or not at all.
+#[derive(Default)]
+pub struct Transaction {
+    staged: Vec<(Prepared, Vec<u8>)>,
+}
+
+/// Why a [`Transaction`] did not complete, and how far undoing it got.
+#[derive(Debug)]
+pub struct RolledBack {
+    /// Index, in the order they were added, of the file that could not be replaced.
+    pub failed: usize,
+    pub error: anyhow::Error,
+    /// How many of the files replaced before it got their original contents back.
+    pub restored: usize,
+    /// The files that could not be restored.
+    pub errors: Vec<(PathBuf, anyhow::Error)>,
+}
+
+impl Transaction {
+    /// Add a file, with the contents to restore if the transaction fails.
+    pub fn push(&mut self, prepared: Prepared, original: Vec<u8>) {
+        self.staged.push((prepared, original));
+    }
+
+    pub fn len(&self) -> usize {
+        self.staged.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.staged.is_empty()
+    }
+
+    /// Rename every file over its target, returning how many were replaced.
+    /// If one can't be, the files replaced before it are rewritten with
+    /// their original contents, newest first, and the temporary files not
+    /// yet renamed are removed.
+    pub fn commit(self) -> Result<usize, RolledBack> {
+        let mut committed: Vec<(PathBuf, Vec<u8>)> = Vec::new();
+        for (index, (prepared, original)) in self.staged.into_iter().enumerate() {
+            let target = prepared.target().to_path_buf();
+            if let Err(error) = prepared.commit() {
+                let mut rolled_back = RolledBack {
+                    failed: index,
+                    error,
+                    restored: 0,
+                    errors: Vec::new(),
+                };
+                for (target, original) in committed.into_iter().rev() {
+                    match write(&target, &original, WriteOptions::default()) {
+                        Ok(()) => rolled_back.restored += 1,
+                        Err(e) => rolled_back.errors.push((target, e)),
+                    }
+                }
+                // The rest of `self.staged` is dropped on return, removing
+                // the temporary files.
+                return Err(rolled_back);
+            }
+            committed.push((target, original));
+        }
+        Ok(committed.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_rename_rolls_back_and_cleans_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut transaction = Transaction::default();
+        for name in ["a.md", "b.md", "c.md"] {
+            let path = dir.path().join(name);
+            fs::write(&path, format!("old {}", name)).unwrap();
+            let prepared = prepare(&path, format!("new {}", name).as_bytes(), WriteOptions::default()).unwrap();
+            transaction.push(prepared, format!("old {}", name).into_bytes());
+        }
+        // A directory can't be replaced by a file.
+        let blocked = dir.path().join("b.md");
+        fs::remove_file(&blocked).unwrap();
+        fs::create_dir(&blocked).unwrap();
+
+        let rolled_back = transaction.commit().unwrap_err();
+        assert_eq!(rolled_back.failed, 1);
+        assert_eq!(rolled_back.restored, 1);
+        assert!(rolled_back.errors.is_empty());
+        assert_eq!(fs::read_to_string(dir.path().join("a.md")).unwrap(), "old a.md");
+        assert!(blocked.is_dir());
+        assert_eq!(fs::read_to_string(dir.path().join("c.md")).unwrap(), "old c.md");
+
+        let mut names: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["a.md", "b.md", "c.md"]);
+    }
+
+    #[test]
+    fn commit_replaces_every_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut transaction = Transaction::default();
+        for name in ["a.md", "b.md"] {
+            let path = dir.path().join(name);
+            fs::write(&path, "old").unwrap();
+            transaction.push(prepare(&path, b"new", WriteOptions::default()).unwrap(), b"old".to_vec());
+        }
+        assert_eq!(transaction.commit().unwrap(), 2);
+        for name in ["a.md", "b.md"] {
+            assert_eq!(fs::read_to_string(dir.path().join(name)).unwrap(), "new");
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Run-stamped backups in a central directory, and restoring from them.
+//!
+//! Every run that modifies files gets its own directory under the backup
+//! root, named after the time it started (`2026-10-17T09-30-00Z`). The
+//! originals are copied below `files/` at their path relative to the working
+//! directory, or below `files/abs/` at their absolute path for files outside
+//! it, and `manifest.toml` records where each copy came from:
+//!
+//! ```toml
+//! created = "2026-10-17T09:30:00Z"
+//!
+//! [[files]]
+//! path = "/home/me/docs/guide.md"
+//! backup = "files/docs/guide.md"
+//! ```
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Component, Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::atomic::{self, WriteOptions};
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.toml";
+const FILES_DIR: &str = "files";
+/// Below `files/`, where files outside the working directory go.
+const ABSOLUTE_DIR: &str = "abs";
+
+/// One backed-up file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// Absolute path of the original file.
+    pub path: PathBuf,
+    /// The copy, relative to the run directory.
+    pub backup: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    created: String,
+    #[serde(default)]
+    files: Vec<Entry>,
+}
+
+/// Entries are appended one `[[files]]` table at a time, so a run that is
+/// interrupted still leaves a readable manifest for what it backed up.
+#[derive(Serialize)]
+struct Appended<'a> {
+    files: [&'a Entry; 1],
+}
+
+/// The backups of the run in progress. The run directory is only created
+/// once the first file is saved, so runs that change nothing leave no trace.
+pub struct Snapshot {
+    root: PathBuf,
+    started: SystemTime,
+    cwd: PathBuf,
+    open: Mutex<Option<OpenRun>>,
+}
+
+struct OpenRun {
+    id: String,
+    dir: PathBuf,
+    manifest: File,
+}
+
+impl Snapshot {
+    pub fn new(root: &Path) -> Result<Self> {
+        Ok(Self {
+            root: std::path::absolute(root)
+                .with_context(|| format!("Could not resolve `{}`", root.display()))?,
+            started: SystemTime::now(),
+            cwd: std::env::current_dir()
+                .and_then(fs::canonicalize)
+                .context("Could not read the current directory")?,
+            open: Mutex::new(None),
+        })
+    }
+
+    /// The backup root, absolute.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The id of this run, once something has been saved.
+    pub fn id(&self) -> Option<String> {
+        self.lock().as_ref().map(|run| run.id.clone())
+    }
+
+    /// Copy `path` into the run before it is modified, returning the copy.
+    /// An existing copy is never overwritten.
+    pub fn save(&self, path: &Path) -> Result<PathBuf> {
+        // Canonical, so `..` and symbolic links can't give two files one copy.
+        let path = fs::canonicalize(path)
+            .with_context(|| format!("Could not resolve `{}`", path.display()))?;
+        let entry = Entry {
+            backup: Path::new(FILES_DIR).join(self.relative(&path)),
+            path,
+        };
+
+        let mut open = self.lock();
+        if open.is_none() {
+            *open = Some(self.open_run()?);
+        }
+        let run = open.as_mut().expect("run was just opened");
+
+        let backup = run.dir.join(&entry.backup);
+        if let Some(parent) = backup.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create `{}`", parent.display()))?;
+        }
+        copy_new(&entry.path, &backup)
+            .with_context(|| format!("Could not create backup at `{}`", backup.display()))?;
+
+        let table = toml::to_string(&Appended { files: [&entry] })?;
+        writeln!(run.manifest, "\n{}", table.trim_end())
+            .and_then(|()| run.manifest.sync_data())
+            .with_context(|| format!("Could not update the manifest of run `{}`", run.id))?;
+        Ok(backup)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<OpenRun>> {
+        self.open.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Where the canonical `path` goes below `files/`: relative to the
+    /// working directory when it is inside it, otherwise below `abs/` at its
+    /// absolute path without the root.
+    fn relative(&self, path: &Path) -> PathBuf {
+        if let Ok(relative) = path.strip_prefix(&self.cwd) {
+            return relative.to_path_buf();
+        }
+        let normal = path.components().filter(|c| matches!(c, Component::Normal(_)));
+        Path::new(ABSOLUTE_DIR).join(normal.collect::<PathBuf>())
+    }
+
+    fn open_run(&self) -> Result<OpenRun> {
+        fs::create_dir_all(&self.root)
+            .with_context(|| format!("Could not create backup directory `{}`", self.root.display()))?;
+
+        let stamp = humantime::format_rfc3339_seconds(self.started).to_string();
+        let base = stamp.replace(':', "-");
+        // Runs started within the same second get a numbered suffix.
+        let mut n = 0;
+        let (id, dir) = loop {
+            let id = if n == 0 { base.clone() } else { format!("{}-{}", base, n) };
+            let dir = self.root.join(&id);
+            match fs::create_dir(&dir) {
+                Ok(()) => break (id, dir),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => n += 1,
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Could not create run `{}`", dir.display()))
+                }
+            }
+        };
+
+        let path = dir.join(MANIFEST_FILE_NAME);
+        let mut manifest = OpenOptions::new()
+            .create_new(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Could not create `{}`", path.display()))?;
+        writeln!(manifest, "created = \"{}\"", stamp)
+            .with_context(|| format!("Could not write `{}`", path.display()))?;
+        Ok(OpenRun { id, dir, manifest })
+    }
+}
+
+/// `path` made canonical, as far as it exists: the missing part of a path
+/// whose file (or directories) were deleted is joined to the canonical form
+/// of the part that is still there.
+fn canonical(path: &Path) -> std::io::Result<PathBuf> {
+    let path = std::path::absolute(path)?;
+    let mut existing = path.as_path();
+    let mut missing = Vec::new();
+    loop {
+        match fs::canonicalize(existing) {
+            Ok(mut resolved) => {
+                resolved.extend(missing.iter().rev());
+                return Ok(resolved);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => match (existing.parent(), existing.file_name()) {
+                (Some(parent), Some(name)) => {
+                    missing.push(name);
+                    existing = parent;
+                }
+                _ => return Err(e),
+            },
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Copy `from` to `to` with its permissions, failing if `to` exists.
+fn copy_new(from: &Path, to: &Path) -> std::io::Result<()> {
+    let mut source = File::open(from)?;
+    let mut target = OpenOptions::new().write(true).create_new(true).open(to)?;
+    std::io::copy(&mut source, &mut target)?;
+    target.set_permissions(source.metadata()?.permissions())?;
+    target.sync_all()
+}
+
+/// A finished (or interrupted) run read back from the backup root.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub id: String,
+    pub dir: PathBuf,
+    pub created: SystemTime,
+    pub files: Vec<Entry>,
+}
+
+impl Run {
+    fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(MANIFEST_FILE_NAME);
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read manifest `{}`", path.display()))?;
+        let manifest: Manifest =
+            toml::from_str(&text).with_context(|| format!("Invalid manifest `{}`", path.display()))?;
+        let created = humantime::parse_rfc3339(&manifest.created)
+            .with_context(|| format!("Invalid `created` time in `{}`", path.display()))?;
+        Ok(Self {
+            id: dir.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+            dir: dir.to_path_buf(),
+            created,
+            files: manifest.files,
+        })
+    }
+
+    /// Entries for `paths` (files, or directories containing them), or every
+    /// entry when `paths` is empty. Paths are resolved like the ones saved,
+    /// so they can go through symbolic links or name deleted files.
+    pub fn select(&self, paths: &[PathBuf]) -> Result<Vec<&Entry>> {
+        let paths = paths
+            .iter()
+            .map(|p| canonical(p).with_context(|| format!("Could not resolve `{}`", p.display())))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self
+            .files
+            .iter()
+            .filter(|entry| paths.is_empty() || paths.iter().any(|p| entry.path.starts_with(p)))
+            .collect())
+    }
+
+    /// Put the backed-up copy of `entry` back in place.
+    pub fn restore(&self, entry: &Entry) -> Result<()> {
+        let backup = self.dir.join(&entry.backup);
+        let contents =
+            fs::read(&backup).with_context(|| format!("Could not read backup `{}`", backup.display()))?;
+        if fs::symlink_metadata(&entry.path).is_ok() {
+            return atomic::write(&entry.path, &contents, WriteOptions::default());
+        }
+
+        if let Some(parent) = entry.path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create `{}`", parent.display()))?;
+        }
+        fs::copy(&backup, &entry.path)
+            .with_context(|| format!("Could not write to file `{}`", entry.path.display()))?;
+        Ok(())
+    }
+}
+
+/// Every run under `root`, oldest first. Directories without a manifest
+/// are not runs and are left out.
+pub fn runs(root: &Path) -> Result<Vec<Run>> {
+    let mut runs = Vec::new();
+    let entries = fs::read_dir(root)
+        .with_context(|| format!("Could not read backup directory `{}`", root.display()))?;
+    for entry in entries {
+        let dir = entry
+            .with_context(|| format!("Could not read backup directory `{}`", root.display()))?
+            .path();
+        if dir.join(MANIFEST_FILE_NAME).is_file() {
+            runs.push(Run::load(&dir)?);
+        }
+    }
+
+    // Suffixed ids sort after the plain one: `...00Z`, `...00Z-1`, ..., `...00Z-10`.
+    runs.sort_by(|a, b| (a.created, a.id.len(), &a.id).cmp(&(b.created, b.id.len(), &b.id)));
+    Ok(runs)
+}
+
+/// How many runs to keep when pruning.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Retention {
+    /// Keep at most this many of the newest runs.
+    pub keep: Option<usize>,
+    /// Remove runs older than this.
+    pub max_age: Option<Duration>,
+}
+
+/// Delete the runs under `root` that `retention` no longer keeps, returning
+/// their ids.
+pub fn prune(root: &Path, retention: Retention) -> Result<Vec<String>> {
+    let runs = runs(root)?;
+    let now = SystemTime::now();
+    let newest_kept = runs.len().saturating_sub(retention.keep.unwrap_or(runs.len()));
+
+    let mut removed = Vec::new();
+    for (index, run) in runs.into_iter().enumerate() {
+        let too_old = retention
+            .max_age
+            .is_some_and(|age| now.duration_since(run.created).is_ok_and(|elapsed| elapsed > age));
+        if index < newest_kept || too_old {
+            fs::remove_dir_all(&run.dir)
+                .with_context(|| format!("Could not remove run `{}`", run.dir.display()))?;
+            removed.push(run.id);
+        }
+    }
+
+    Ok(removed)
+}
@@ -0,0 +1,303 @@
+//! Whitespace and punctuation repair around deleted emoji sequences.
+//!
+//! Only whitespace touching a deletion is ever changed, and only inside the
+//! prose region the deletion is in, so Markdown syntax and indentation
+//! elsewhere on the line are left as they were.
+
+use std::ops::Range;
+
+/// What to do in place of one run of deleted sequences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Removal {
+    /// Bytes to drop: the run plus any whitespace around it that goes too.
+    pub range: Range<usize>,
+    /// Whether a space must be put in the run's place to keep two words apart.
+    pub space: bool,
+}
+
+/// Spaces and tabs, including the non-breaking kinds.
+fn is_blank(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\u{A0}' | '\u{202F}' | '\u{2007}' | '\u{3000}')
+}
+
+fn is_line_break(c: char) -> bool {
+    matches!(c, '\n' | '\r')
+}
+
+/// Punctuation that attaches to the word before it.
+fn is_closing(c: char) -> bool {
+    matches!(c, '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '%' | '…' | '»' | '”' | '’')
+}
+
+/// Punctuation that attaches to the word after it.
+fn is_opening(c: char) -> bool {
+    matches!(c, '(' | '[' | '{' | '¿' | '¡' | '«' | '“' | '‘')
+}
+
+/// Markdown emphasis and strikethrough delimiters.
+fn is_delimiter(c: char) -> bool {
+    matches!(c, '*' | '_' | '~')
+}
+
+/// Whether `text` ends with a delimiter run that opens emphasis, as in
+/// `**bold`: one at the start of a line or after a blank or opening
+/// punctuation.
+fn ends_with_opener(text: &str) -> bool {
+    let before = text.trim_end_matches(is_delimiter);
+    before.len() < text.len()
+        && before
+            .chars()
+            .next_back()
+            .is_none_or(|c| is_blank(c) || is_line_break(c) || is_opening(c))
+}
+
+/// Whether `text` starts with a delimiter run that closes emphasis, as in
+/// `bold**`: one at the end of a line or before a blank or closing
+/// punctuation.
+fn starts_with_closer(text: &str) -> bool {
+    let after = text.trim_start_matches(is_delimiter);
+    after.len() < text.len()
+        && after
+            .chars()
+            .next()
+            .is_none_or(|c| is_blank(c) || is_line_break(c) || is_closing(c))
+}
+
+/// Length in bytes of the blanks ending at `end`, not reaching below `floor`.
+fn blanks_before(text: &str, floor: usize, end: usize) -> usize {
+    text[floor..end]
+        .chars()
+        .rev()
+        .take_while(|&c| is_blank(c))
+        .map(char::len_utf8)
+        .sum()
+}
+
+/// Length in bytes of the blanks starting at `start`, not reaching `ceiling`.
+fn blanks_after(text: &str, start: usize, ceiling: usize) -> usize {
+    text[start..ceiling]
+        .chars()
+        .take_while(|&c| is_blank(c))
+        .map(char::len_utf8)
+        .sum()
+}
+
+/// Extend `run` over the blanks between it and the sequences that follow in
+/// `next`, so `a 🚀 🎉 b` is repaired as one deletion. Returns the new end
+/// and how many of `next` were absorbed.
+pub(crate) fn absorb(
+    text: &str,
+    region: &Range<usize>,
+    end: usize,
+    next: impl IntoIterator<Item = Range<usize>>,
+) -> (usize, usize) {
+    let mut end = end;
+    let mut absorbed = 0;
+    for range in next {
+        let gap_end = end + blanks_after(text, end, region.end);
+        if range.start != gap_end {
+            break;
+        }
+        end = range.end;
+        absorbed += 1;
+    }
+    (end, absorbed)
+}
+
+/// How to delete `run`, which lies in the prose `region`; nothing before
+/// `floor` (the end of the previous deletion) is touched.
+///
+/// - On a line of its own, the blanks around the run go with it.
+/// - At the start of a line, the blanks after it go; at the end of a line,
+///   the blanks on both sides.
+/// - Between two blanks, one of them goes.
+/// - Between a blank and closing punctuation (`Done 🎉!`) or opening
+///   punctuation and a blank (`(🚀 note)`), the blank goes; emphasis
+///   delimiters count as punctuation (`**🚀 Launch**`).
+/// - Between two words with no blank (`foo🚀bar`), a space is put in.
+pub(crate) fn removal(text: &str, region: &Range<usize>, run: Range<usize>, floor: usize) -> Removal {
+    let floor = floor.max(region.start);
+
+    // Neighbours are judged on the whole text, but only blanks inside the
+    // region can be removed.
+    let left_blanks = blanks_before(text, 0, run.start);
+    let right_blanks = blanks_after(text, run.end, text.len());
+    let before = &text[..run.start - left_blanks];
+    let after = &text[run.end + right_blanks..];
+    let left = before.chars().next_back();
+    let right = after.chars().next();
+    let removable_left = blanks_before(text, floor, run.start);
+    let removable_right = blanks_after(text, run.end, region.end);
+
+    let line_start = left.is_none_or(is_line_break);
+    let line_end = right.is_none_or(is_line_break);
+    let remove = |before: usize, after: usize| Removal {
+        range: run.start - before..run.end + after,
+        space: false,
+    };
+
+    match (line_start, line_end) {
+        (true, true) => remove(removable_left, removable_right),
+        // Keep the indentation of the line.
+        (true, false) => remove(0, removable_right),
+        (false, true) => remove(removable_left, removable_right),
+        (false, false) if left_blanks > 0 && right_blanks > 0 => {
+            if removable_right == right_blanks {
+                remove(0, removable_right)
+            } else if removable_left == left_blanks {
+                remove(removable_left, 0)
+            } else {
+                remove(0, 0)
+            }
+        }
+        (false, false) if left_blanks > 0 => {
+            if (right.is_some_and(is_closing) || starts_with_closer(after)) && removable_left == left_blanks {
+                remove(removable_left, 0)
+            } else {
+                remove(0, 0)
+            }
+        }
+        (false, false) if right_blanks > 0 => {
+            if (left.is_some_and(is_opening) || ends_with_opener(before)) && removable_right == right_blanks {
+                remove(0, removable_right)
+            } else {
+                remove(0, 0)
+            }
+        }
+        (false, false) => Removal {
+            range: run,
+            space: left.is_some_and(char::is_alphanumeric) && right.is_some_and(char::is_alphanumeric),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emoji;
+
+    /// `text` with the sequences in `region` deleted, repaired the way
+    /// stripping does it.
+    fn clean(text: &str, region: Range<usize>) -> String {
+        let runs: Vec<_> = emoji::sequences(&text[region.clone()])
+            .map(|r| region.start + r.start..region.start + r.end)
+            .collect();
+        let mut out = String::new();
+        let mut last = 0;
+        let mut i = 0;
+        while i < runs.len() {
+            let (end, absorbed) = absorb(text, &region, runs[i].end, runs[i + 1..].iter().cloned());
+            let removal = removal(text, &region, runs[i].start..end, last);
+            out.push_str(&text[last..removal.range.start]);
+            if removal.space {
+                out.push(' ');
+            }
+            last = removal.range.end;
+            i += 1 + absorbed;
+        }
+        out.push_str(&text[last..]);
+        out
+    }
+
+    fn check(cases: &[(&str, &str)]) {
+        for (input, expected) in cases {
+            assert_eq!(clean(input, 0..input.len()), *expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn start_and_end_of_line() {
+        check(&[
+            ("🚀 Launch", "Launch"),
+            ("Launch 🚀", "Launch"),
+            ("🚀", ""),
+            ("  🚀  ", ""),
+            ("  🚀 Launch", "  Launch"),
+            ("Launch 🚀\nNext", "Launch\nNext"),
+            ("Launch\n🚀 Next\n", "Launch\nNext\n"),
+            ("Launch 🚀\r\nNext", "Launch\r\nNext"),
+        ]);
+    }
+
+    #[test]
+    fn between_words() {
+        check(&[
+            ("Ship 🚀 it", "Ship it"),
+            ("Ship 🚀  it", "Ship it"),
+            ("foo🚀bar", "foo bar"),
+            ("foo🚀 bar", "foo bar"),
+            ("foo 🚀bar", "foo bar"),
+        ]);
+    }
+
+    #[test]
+    fn tabs_and_non_breaking_spaces() {
+        check(&[
+            ("Ship\t🚀\tit", "Ship\tit"),
+            ("Ship 🚀\tit", "Ship it"),
+            ("\t🚀 Launch", "\tLaunch"),
+            ("Ship\u{A0}🚀\u{A0}it", "Ship\u{A0}it"),
+            ("Done\u{A0}🎉!", "Done!"),
+            ("Launch\u{202F}🚀", "Launch"),
+        ]);
+    }
+
+    #[test]
+    fn punctuation_on_either_side() {
+        check(&[
+            ("Done 🎉!", "Done!"),
+            ("Done 🎉.", "Done."),
+            ("Done 🎉, then", "Done, then"),
+            ("Done🎉!", "Done!"),
+            ("(🚀 note)", "(note)"),
+            ("(note 🚀)", "(note)"),
+            ("«🚀 note»", "«note»"),
+            ("¿🚀 Qué?", "¿Qué?"),
+            ("(🚀)", "()"),
+            ("a - 🚀 b", "a - b"),
+        ]);
+    }
+
+    #[test]
+    fn runs_of_emojis() {
+        check(&[
+            ("Ship 🚀🎉 it", "Ship it"),
+            ("Ship 🚀 🎉 it", "Ship it"),
+            ("Ship 🚀\t🎉  ✨ it", "Ship it"),
+            ("🚀 🎉 Launch", "Launch"),
+            ("Launch 🚀 🎉", "Launch"),
+            ("foo🚀🎉bar", "foo bar"),
+            ("Done 🎉 🎉!", "Done!"),
+            ("🚀 a 🎉 b ✨", "a b"),
+        ]);
+    }
+
+    #[test]
+    fn region_boundaries_next_to_markdown_syntax() {
+        // The prose region is the middle part; the rest is Markdown syntax.
+        for (before, prose, after, expected) in [
+            ("# ", "🚀 Launch", "", "# Launch"),
+            ("# ", "Launch 🚀", "", "# Launch"),
+            ("- ", "🚀 item", "", "- item"),
+            ("> ", "🚀 Quote", "", "> Quote"),
+            ("1. ", "🚀 Step", "", "1. Step"),
+            ("**", "🚀 Launch", "**", "**Launch**"),
+            ("**", "Launch 🚀", "**", "**Launch**"),
+            ("_", "🚀 Launch", "_", "_Launch_"),
+            ("~~", "Launch 🚀", "~~", "~~Launch~~"),
+            ("[", "🚀 docs", "](url)", "[docs](url)"),
+            ("[", "docs 🚀", "](url)", "[docs](url)"),
+            ("| ", "🚀 done", " |", "| done |"),
+            ("| ", "done 🚀", " |", "| done |"),
+            // Blanks outside the region are never touched.
+            ("a **b**", "🚀 c", "", "a **b** c"),
+            ("`x` ", "🚀 y", "", "`x` y"),
+            ("", "a 🚀", " `x`", "a `x`"),
+            ("", "a 🚀", "**b** c", "a **b** c"),
+        ] {
+            let text = format!("{}{}{}", before, prose, after);
+            let region = before.len()..before.len() + prose.len();
+            assert_eq!(clean(&text, region), expected, "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! `.remoji.toml` discovery and merging.
+//!
+//! The settings for a file come from every `.remoji.toml` between the
+//! filesystem root and the file's directory, applied outermost first so nested
+//! files override their parents, and finally from the command-line overrides.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Context as _, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+
+use crate::{
+    filetype::{FileTypes, Processor},
+    Context, Options, Pattern, Policy, Replacement, Structure,
+};
+
+pub const FILE_NAME: &str = ".remoji.toml";
+
+/// One configuration file as written on disk. Every field is optional so a
+/// nested file only overrides what it sets.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    /// Globs (relative to the config file) of files to process.
+    pub include: Option<Vec<String>>,
+    /// Globs (relative to the config file) of files to leave alone.
+    pub exclude: Option<Vec<String>>,
+    /// Emojis to keep: sequences, `U+XXXX[..U+YYYY]` ranges, `group:<name>`
+    /// or `subgroup:<name>`.
+    pub allow: Option<Vec<Pattern>>,
+    /// Emojis to strip even when a broader `allow` pattern matches.
+    pub deny: Option<Vec<Pattern>>,
+    pub replace: Option<Replacement>,
+    /// Extra `"*.ext"` or file name mappings to `markdown`, `plain` or `ignore`.
+    /// These add to the parents' mappings instead of replacing them.
+    pub file_types: Option<HashMap<String, Processor>>,
+    /// Markdown contexts whose prose is left alone.
+    pub skip: Option<Vec<Context>>,
+    /// `warn`, `delete` or `name` for each kind of element that deleting
+    /// emojis leaves empty. These add to the parents' settings.
+    pub leftovers: Option<HashMap<Structure, Policy>>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Could not read config `{}`", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("Invalid config `{}`", path.display()))
+    }
+}
+
+/// Globs together with the directory they are relative to.
+#[derive(Debug, Clone)]
+struct Patterns {
+    base: PathBuf,
+    set: GlobSet,
+}
+
+impl Patterns {
+    fn new(base: &Path, globs: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for glob in globs {
+            builder.add(Glob::new(glob).with_context(|| format!("Invalid glob `{}`", glob))?);
+        }
+        Ok(Self {
+            base: base.to_path_buf(),
+            set: builder.build()?,
+        })
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        path.strip_prefix(&self.base).is_ok_and(|relative| self.set.is_match(relative))
+    }
+}
+
+/// The effective settings for files in one directory.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    pub options: Options,
+    pub file_types: FileTypes,
+    include: Option<Patterns>,
+    exclude: Option<Patterns>,
+}
+
+impl Settings {
+    fn apply(&mut self, config: &Config, base: &Path) -> Result<()> {
+        if let Some(include) = &config.include {
+            self.include = Some(Patterns::new(base, include)?);
+        }
+        if let Some(exclude) = &config.exclude {
+            self.exclude = Some(Patterns::new(base, exclude)?);
+        }
+        if let Some(allow) = &config.allow {
+            self.options.allow = allow.clone();
+        }
+        if let Some(deny) = &config.deny {
+            self.options.deny = deny.clone();
+        }
+        if let Some(replace) = &config.replace {
+            self.options.replace = replace.clone();
+        }
+        for (pattern, processor) in config.file_types.iter().flatten() {
+            self.file_types.insert(pattern, *processor);
+        }
+        if let Some(skip) = &config.skip {
+            self.options.skip = skip.clone();
+        }
+        for (structure, policy) in config.leftovers.iter().flatten() {
+            self.options
+                .leftovers
+                .set(*structure, *policy)
+                .map_err(|e| anyhow::anyhow!("Invalid `leftovers` setting: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Whether a file found while scanning a directory should be processed.
+    pub fn selects(&self, path: &Path) -> bool {
+        let Ok(path) = std::path::absolute(path) else {
+            return false;
+        };
+
+        self.file_types.syntax(&path).is_some()
+            && self.include.as_ref().is_none_or(|p| p.is_match(&path))
+            && !self.exclude.as_ref().is_some_and(|p| p.is_match(&path))
+    }
+
+    /// Stripping options for `path`, with the syntax its file type maps to.
+    /// Files of no known type are treated as Markdown.
+    pub fn options_for(&self, path: &Path) -> Options {
+        Options {
+            syntax: self.file_types.syntax(path).unwrap_or_default(),
+            ..self.options.clone()
+        }
+    }
+}
+
+/// Finds, parses and caches the config files that apply to each directory.
+pub struct Loader {
+    overrides: Config,
+    discover: bool,
+    configs: HashMap<PathBuf, Option<Arc<Config>>>,
+    settings: HashMap<PathBuf, Arc<Settings>>,
+}
+
+impl Loader {
+    /// `overrides` come from the command line and win over every file; with
+    /// `discover` off, no `.remoji.toml` is read at all.
+    pub fn new(overrides: Config, discover: bool) -> Self {
+        Self {
+            overrides,
+            discover,
+            configs: HashMap::new(),
+            settings: HashMap::new(),
+        }
+    }
+
+    /// Settings for `path`, a file or a directory.
+    pub fn settings_for(&mut self, path: &Path) -> Result<Arc<Settings>> {
+        let path = std::path::absolute(path)
+            .with_context(|| format!("Could not resolve `{}`", path.display()))?;
+        let dir = if path.is_dir() {
+            path
+        } else {
+            path.parent().map(Path::to_path_buf).unwrap_or_default()
+        };
+
+        if let Some(settings) = self.settings.get(&dir) {
+            return Ok(Arc::clone(settings));
+        }
+
+        let mut settings = Settings::default();
+        if self.discover {
+            let mut ancestors: Vec<_> = dir.ancestors().collect();
+            ancestors.reverse();
+            for ancestor in ancestors {
+                if let Some(config) = self.config_in(ancestor)? {
+                    settings.apply(&config, ancestor)?;
+                }
+            }
+        }
+        let cwd = std::env::current_dir().context("Could not read the current directory")?;
+        settings.apply(&self.overrides, &cwd)?;
+
+        let settings = Arc::new(settings);
+        self.settings.insert(dir, Arc::clone(&settings));
+        Ok(settings)
+    }
+
+    fn config_in(&mut self, dir: &Path) -> Result<Option<Arc<Config>>> {
+        if let Some(config) = self.configs.get(dir) {
+            return Ok(config.clone());
+        }
+
+        let path = dir.join(FILE_NAME);
+        let config = if path.is_file() {
+            Some(Arc::new(Config::load(&path)?))
+        } else {
+            None
+        };
+        self.configs.insert(dir.to_path_buf(), config.clone());
+        Ok(config)
+    }
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use similar::TextDiff;
+
+/// Unified diff between `original` and `cleaned`, with `a/` and `b/` prefixes so
+/// the output applies with `git apply` or `patch -p1`. Empty when nothing changed.
+pub fn unified(path: &Path, original: &str, cleaned: &str) -> String {
+    if original == cleaned {
+        return String::new();
+    }
+
+    let name = path.strip_prefix("./").unwrap_or(path).display().to_string();
+    TextDiff::from_lines(original, cleaned)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", name), &format!("b/{}", name))
+        .to_string()
+}
+
+/// Wrap the lines of a unified diff in ANSI colors for terminal output.
+pub fn colorize(diff: &str) -> String {
+    let mut out = String::with_capacity(diff.len());
+    for line in diff.lines() {
+        let color = if line.starts_with("---") || line.starts_with("+++") {
+            "1"
+        } else if line.starts_with("@@") {
+            "36"
+        } else if line.starts_with('+') {
+            "32"
+        } else if line.starts_with('-') {
+            "31"
+        } else {
+            ""
+        };
+        if color.is_empty() {
+            out.push_str(line);
+        } else {
+            out.push_str(&format!("\x1b[{}m{}\x1b[0m", color, line));
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,312 @@
+//! Emoji sequence matching.
+//!
+//! A hand-written scanner over static Unicode tables, run once over the text.
+//! It recognizes, as single units:
+//!
+//! - regional-indicator pairs (flags);
+//! - keycaps: `0`-`9`, `#` or `*`, an optional VS16 and U+20E3;
+//! - elements joined with ZWJ, where an element is an Extended_Pictographic or
+//!   Emoji_Presentation codepoint followed by any skin-tone modifiers,
+//!   variation selectors and tag sequences (subdivision flags).
+//!
+//! Codepoints that are text by default, such as `©`, `™` and `↔`, only
+//! start a sequence when a VS16, a skin tone or a ZWJ follows them. Plain
+//! digits, `#` and `*` never match on their own.
+
+use std::ops::Range;
+
+mod tables;
+
+const ZWJ: char = '\u{200D}';
+const KEYCAP: char = '\u{20E3}';
+const VS15: char = '\u{FE0E}';
+const VS16: char = '\u{FE0F}';
+const TAG_END: char = '\u{E007F}';
+
+fn in_table(table: &[(char, char)], c: char) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn is_base(c: char) -> bool {
+    // Nothing below the copyright sign is pictographic.
+    c >= '\u{A9}' && in_table(tables::EMOJI_BASE, c)
+}
+
+fn is_emoji_presentation(c: char) -> bool {
+    in_table(tables::EMOJI_PRESENTATION, c)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_modifier(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+fn is_tag(c: char) -> bool {
+    ('\u{E0020}'..='\u{E007E}').contains(&c)
+}
+
+fn is_keycap_base(c: char) -> bool {
+    c.is_ascii_digit() || c == '#' || c == '*'
+}
+
+/// Length in bytes of the emoji sequence starting at the beginning of `s`, if any.
+fn sequence_len(s: &str) -> Option<usize> {
+    let mut chars = s.chars();
+    let first = chars.next()?;
+
+    if is_keycap_base(first) {
+        let rest = chars.as_str();
+        let rest = rest.strip_prefix(VS16).unwrap_or(rest);
+        return rest.strip_prefix(KEYCAP).map(|rest| s.len() - rest.len());
+    }
+
+    if is_regional_indicator(first) && chars.clone().next().is_some_and(is_regional_indicator) {
+        return Some(first.len_utf8() * 2);
+    }
+
+    let (mut end, emoji) = element_len(s)?;
+    let mut joined = false;
+    while let Some(rest) = s[end..].strip_prefix(ZWJ) {
+        match element_len(rest) {
+            Some((len, _)) => {
+                end += ZWJ.len_utf8() + len;
+                joined = true;
+            }
+            None => break,
+        }
+    }
+    (emoji || joined).then_some(end)
+}
+
+/// Length in bytes of one element (base plus modifiers) at the start of `s`,
+/// and whether it is shown as an emoji: its base is by default, or a VS16 or
+/// a skin tone follows it.
+fn element_len(s: &str) -> Option<(usize, bool)> {
+    let mut chars = s.chars();
+    let base = chars.next().filter(|&c| is_base(c))?;
+    let mut emoji = is_emoji_presentation(base);
+
+    loop {
+        let rest = chars.as_str();
+        match chars.next() {
+            Some(c) if is_modifier(c) || c == VS16 => emoji = true,
+            Some(VS15) => {}
+            Some(c) if is_tag(c) => {
+                // A tag run only counts when it is terminated.
+                let tags = rest.trim_start_matches(is_tag);
+                match tags.strip_prefix(TAG_END) {
+                    Some(after) => chars = after.chars(),
+                    None => return Some((s.len() - rest.len(), emoji)),
+                }
+            }
+            _ => return Some((s.len() - rest.len(), emoji)),
+        }
+    }
+}
+
+/// Iterator over the byte ranges of the emoji sequences in a text.
+pub(crate) struct Sequences<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Iterator for Sequences<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let bytes = self.text.as_bytes();
+        while self.pos < bytes.len() {
+            let start = self.pos;
+            let byte = bytes[start];
+
+            if byte.is_ascii() {
+                self.pos += 1;
+                // A keycap base must be followed by VS16 or U+20E3, never ASCII.
+                if !is_keycap_base(byte as char) || bytes.get(start + 1).is_none_or(u8::is_ascii) {
+                    continue;
+                }
+            }
+
+            let rest = &self.text[start..];
+            if let Some(len) = sequence_len(rest) {
+                self.pos = start + len;
+                return Some(start..start + len);
+            }
+            if !byte.is_ascii() {
+                self.pos += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        None
+    }
+}
+
+/// Byte ranges of every emoji sequence in `text`.
+pub(crate) fn sequences(text: &str) -> Sequences<'_> {
+    Sequences { text, pos: 0 }
+}
+
+pub(crate) fn without_variation_selectors(sequence: &str) -> String {
+    sequence.chars().filter(|c| !matches!(c, '\u{FE0E}' | '\u{FE0F}')).collect()
+}
+
+/// Bundled CLDR data for a sequence, tolerating missing or extra VS16.
+pub(crate) fn lookup(sequence: &str) -> Option<&'static emojis::Emoji> {
+    emojis::get(sequence)
+        .or_else(|| emojis::get(&without_variation_selectors(sequence)))
+        .or_else(|| emojis::get(&format!("{}\u{FE0F}", without_variation_selectors(sequence))))
+}
+
+/// CLDR subgroup names, such as `face-smiling`, in order.
+pub(crate) fn subgroups() -> &'static [&'static str] {
+    tables::SUBGROUPS
+}
+
+/// CLDR subgroup of a sequence, tolerating skin tones and missing or extra VS16.
+pub(crate) fn subgroup(sequence: &str) -> Option<&'static str> {
+    let emoji = lookup(sequence)?;
+    let base = emoji.with_skin_tone(emojis::SkinTone::Default).unwrap_or(emoji);
+    let i = tables::SEQUENCE_SUBGROUPS
+        .binary_search_by_key(&base.as_str(), |&(sequence, _)| sequence)
+        .ok()?;
+    Some(tables::SUBGROUPS[tables::SEQUENCE_SUBGROUPS[i].1 as usize])
+}
+
+/// The codepoints of a sequence as `U+XXXX`, separated by spaces.
+pub(crate) fn codepoints(sequence: &str) -> String {
+    sequence
+        .chars()
+        .map(|c| format!("U+{:04X}", c as u32))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<&str> {
+        sequences(text).map(|range| &text[range]).collect()
+    }
+
+    #[test]
+    fn digits_hash_and_star_alone_are_not_emojis() {
+        for text in [
+            "0123456789",
+            "Call 555-0100, ext. #42 *now*",
+            "# Heading\n\n* item\n1. step",
+            "a*b#c9",
+            // VS16 without the keycap mark.
+            "1\u{FE0F} #\u{FE0F}",
+            // Text by default.
+            "Copyright \u{A9} 2024 Acme\u{2122}. Press \u{2194} to swap.",
+            "\u{AE} \u{203C} \u{25B6} \u{2764} \u{2764}\u{FE0E}",
+        ] {
+            assert_eq!(found(text), Vec::<&str>::new(), "{:?}", text);
+            assert!(matches!(crate::strip(text), std::borrow::Cow::Borrowed(t) if t == text), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn keycaps_match_with_and_without_vs16() {
+        for keycap in ["1\u{FE0F}\u{20E3}", "1\u{20E3}", "#\u{FE0F}\u{20E3}", "#\u{20E3}", "*\u{FE0F}\u{20E3}", "*\u{20E3}"] {
+            let text = format!("Press 0{}5 now", keycap);
+            assert_eq!(found(&text), [keycap], "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn sequences_match_as_one_unit() {
+        for sequence in [
+            // ZWJ: family, rainbow flag, technologist with a skin tone.
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+            "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+            "\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}",
+            // Skin tone.
+            "\u{1F44D}\u{1F3FD}",
+            // Flag.
+            "\u{1F1EB}\u{1F1F7}",
+            // Tag sequence: the flag of England.
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+            // Text by default, shown as emojis.
+            "\u{A9}\u{FE0F}",
+            "\u{2764}\u{FE0F}",
+            "\u{261D}\u{1F3FD}",
+            "\u{2764}\u{FE0F}\u{200D}\u{1F525}",
+            "\u{2764}\u{200D}\u{1F525}",
+            "\u{1F9D1}\u{200D}\u{2695}\u{FE0F}",
+            // Emoji by default, shown as text.
+            "\u{1F680}\u{FE0E}",
+        ] {
+            let text = format!("a{}b", sequence);
+            assert_eq!(found(&text), [sequence], "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn adjacent_sequences_stay_apart() {
+        assert_eq!(
+            found("\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}\u{1F680}\u{1F389}"),
+            ["\u{1F1EB}\u{1F1F7}", "\u{1F1E9}\u{1F1EA}", "\u{1F680}", "\u{1F389}"]
+        );
+    }
+
+    #[test]
+    fn trailing_zwj_is_left_out() {
+        assert_eq!(found("\u{1F680}\u{200D}x"), ["\u{1F680}"]);
+    }
+
+    #[test]
+    fn unterminated_tag_run_is_left_out() {
+        let text = "\u{1F3F4}\u{E0067}\u{E0062} \u{1F680}";
+        assert_eq!(found(text), ["\u{1F3F4}", "\u{1F680}"]);
+    }
+
+    #[test]
+    fn subgroups_ignore_skin_tones_and_vs16() {
+        for (sequence, expected) in [
+            ("\u{1F600}", "face-smiling"),
+            ("\u{1F44D}", "hand-fingers-closed"),
+            ("\u{1F44D}\u{1F3FD}", "hand-fingers-closed"),
+            ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}", "person-role"),
+            ("\u{2764}", "heart"),
+            ("\u{2764}\u{FE0F}", "heart"),
+            ("1\u{20E3}", "keycap"),
+            ("\u{1F1EB}\u{1F1F7}", "country-flag"),
+            ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}", "subdivision-flag"),
+        ] {
+            assert_eq!(subgroup(sequence), Some(expected), "{:?}", sequence);
+        }
+        assert_eq!(subgroup("\u{1F3FD}"), None);
+    }
+
+    #[test]
+    fn subgroup_table_is_sorted() {
+        for pair in tables::SEQUENCE_SUBGROUPS.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{:?}", pair);
+        }
+        assert!(tables::SEQUENCE_SUBGROUPS
+            .iter()
+            .all(|&(_, i)| (i as usize) < tables::SUBGROUPS.len()));
+    }
+
+    #[test]
+    fn base_table_is_sorted_and_disjoint() {
+        for pair in tables::EMOJI_BASE.windows(2) {
+            let ((start, end), (next, _)) = (pair[0], pair[1]);
+            assert!(start <= end && (end as u32) + 1 < next as u32, "{:?}", pair);
+        }
+    }
+}
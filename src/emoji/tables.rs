@@ -0,0 +1,2183 @@
+// Derived from the Unicode 16.0.0 emoji-data.txt and emoji-test.txt.
+// Generated by scripts/emoji-tables.py.
+
+/// Codepoints that can start an emoji element, as sorted inclusive ranges:
+/// the Extended_Pictographic and Emoji_Presentation properties.
+pub(super) const EMOJI_BASE: &[(char, char)] = &[
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2605}'),
+    ('\u{2607}', '\u{2612}'),
+    ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'),
+    ('\u{2708}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F000}', '\u{1F0FF}'),
+    ('\u{1F10D}', '\u{1F10F}'),
+    ('\u{1F12F}', '\u{1F12F}'),
+    ('\u{1F16C}', '\u{1F171}'),
+    ('\u{1F17E}', '\u{1F17F}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1AD}', '\u{1F1FF}'),
+    ('\u{1F201}', '\u{1F20F}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F23A}'),
+    ('\u{1F23C}', '\u{1F23F}'),
+    ('\u{1F249}', '\u{1F53D}'),
+    ('\u{1F546}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6FF}'),
+    ('\u{1F774}', '\u{1F77F}'),
+    ('\u{1F7D5}', '\u{1F7FF}'),
+    ('\u{1F80C}', '\u{1F80F}'),
+    ('\u{1F848}', '\u{1F84F}'),
+    ('\u{1F85A}', '\u{1F85F}'),
+    ('\u{1F888}', '\u{1F88F}'),
+    ('\u{1F8AE}', '\u{1F8FF}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1FAFF}'),
+    ('\u{1FC00}', '\u{1FFFD}'),
+];
+
+/// Codepoints shown as emojis by default, as sorted inclusive ranges: the
+/// Emoji_Presentation property. The others are text unless a VS16, a
+/// skin tone or a ZWJ follows them.
+pub(super) const EMOJI_PRESENTATION: &[(char, char)] = &[
+    ('\u{231A}', '\u{231B}'),
+    ('\u{23E9}', '\u{23EC}'),
+    ('\u{23F0}', '\u{23F0}'),
+    ('\u{23F3}', '\u{23F3}'),
+    ('\u{25FD}', '\u{25FE}'),
+    ('\u{2614}', '\u{2615}'),
+    ('\u{2648}', '\u{2653}'),
+    ('\u{267F}', '\u{267F}'),
+    ('\u{2693}', '\u{2693}'),
+    ('\u{26A1}', '\u{26A1}'),
+    ('\u{26AA}', '\u{26AB}'),
+    ('\u{26BD}', '\u{26BE}'),
+    ('\u{26C4}', '\u{26C5}'),
+    ('\u{26CE}', '\u{26CE}'),
+    ('\u{26D4}', '\u{26D4}'),
+    ('\u{26EA}', '\u{26EA}'),
+    ('\u{26F2}', '\u{26F3}'),
+    ('\u{26F5}', '\u{26F5}'),
+    ('\u{26FA}', '\u{26FA}'),
+    ('\u{26FD}', '\u{26FD}'),
+    ('\u{2705}', '\u{2705}'),
+    ('\u{270A}', '\u{270B}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{1F004}', '\u{1F004}'),
+    ('\u{1F0CF}', '\u{1F0CF}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1E6}', '\u{1F1FF}'),
+    ('\u{1F201}', '\u{1F201}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F236}'),
+    ('\u{1F238}', '\u{1F23A}'),
+    ('\u{1F250}', '\u{1F251}'),
+    ('\u{1F300}', '\u{1F320}'),
+    ('\u{1F32D}', '\u{1F335}'),
+    ('\u{1F337}', '\u{1F37C}'),
+    ('\u{1F37E}', '\u{1F393}'),
+    ('\u{1F3A0}', '\u{1F3CA}'),
+    ('\u{1F3CF}', '\u{1F3D3}'),
+    ('\u{1F3E0}', '\u{1F3F0}'),
+    ('\u{1F3F4}', '\u{1F3F4}'),
+    ('\u{1F3F8}', '\u{1F43E}'),
+    ('\u{1F440}', '\u{1F440}'),
+    ('\u{1F442}', '\u{1F4FC}'),
+    ('\u{1F4FF}', '\u{1F53D}'),
+    ('\u{1F54B}', '\u{1F54E}'),
+    ('\u{1F550}', '\u{1F567}'),
+    ('\u{1F57A}', '\u{1F57A}'),
+    ('\u{1F595}', '\u{1F596}'),
+    ('\u{1F5A4}', '\u{1F5A4}'),
+    ('\u{1F5FB}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6C5}'),
+    ('\u{1F6CC}', '\u{1F6CC}'),
+    ('\u{1F6D0}', '\u{1F6D2}'),
+    ('\u{1F6D5}', '\u{1F6D7}'),
+    ('\u{1F6DC}', '\u{1F6DF}'),
+    ('\u{1F6EB}', '\u{1F6EC}'),
+    ('\u{1F6F4}', '\u{1F6FC}'),
+    ('\u{1F7E0}', '\u{1F7EB}'),
+    ('\u{1F7F0}', '\u{1F7F0}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1F9FF}'),
+    ('\u{1FA70}', '\u{1FA7C}'),
+    ('\u{1FA80}', '\u{1FA89}'),
+    ('\u{1FA8F}', '\u{1FAC6}'),
+    ('\u{1FACE}', '\u{1FADC}'),
+    ('\u{1FADF}', '\u{1FAE9}'),
+    ('\u{1FAF0}', '\u{1FAF8}'),
+];
+
+/// CLDR emoji subgroups, in order.
+pub(super) const SUBGROUPS: &[&str] = &[
+    "face-smiling",
+    "face-affection",
+    "face-tongue",
+    "face-hand",
+    "face-neutral-skeptical",
+    "face-sleepy",
+    "face-unwell",
+    "face-hat",
+    "face-glasses",
+    "face-concerned",
+    "face-negative",
+    "face-costume",
+    "cat-face",
+    "monkey-face",
+    "heart",
+    "emotion",
+    "hand-fingers-open",
+    "hand-fingers-partial",
+    "hand-single-finger",
+    "hand-fingers-closed",
+    "hands",
+    "hand-prop",
+    "body-parts",
+    "person",
+    "person-gesture",
+    "person-role",
+    "person-fantasy",
+    "person-activity",
+    "person-sport",
+    "person-resting",
+    "family",
+    "person-symbol",
+    "animal-mammal",
+    "animal-bird",
+    "animal-amphibian",
+    "animal-reptile",
+    "animal-marine",
+    "animal-bug",
+    "plant-flower",
+    "plant-other",
+    "food-fruit",
+    "food-vegetable",
+    "food-prepared",
+    "food-asian",
+    "food-sweet",
+    "drink",
+    "dishware",
+    "place-map",
+    "place-geographic",
+    "place-building",
+    "place-religious",
+    "place-other",
+    "transport-ground",
+    "transport-water",
+    "transport-air",
+    "hotel",
+    "time",
+    "sky & weather",
+    "event",
+    "award-medal",
+    "sport",
+    "game",
+    "arts & crafts",
+    "clothing",
+    "sound",
+    "music",
+    "musical-instrument",
+    "phone",
+    "computer",
+    "light & video",
+    "book-paper",
+    "money",
+    "mail",
+    "writing",
+    "office",
+    "lock",
+    "tool",
+    "science",
+    "medical",
+    "household",
+    "other-object",
+    "transport-sign",
+    "warning",
+    "arrow",
+    "religion",
+    "zodiac",
+    "av-symbol",
+    "gender",
+    "math",
+    "punctuation",
+    "currency",
+    "other-symbol",
+    "keycap",
+    "alphanum",
+    "geometric",
+    "flag",
+    "country-flag",
+    "subdivision-flag",
+];
+
+/// Fully-qualified sequences without skin tones, sorted, and the index of
+/// their subgroup in [`SUBGROUPS`].
+pub(super) const SEQUENCE_SUBGROUPS: &[(&str, u8)] = &[
+    ("\u{23}\u{FE0F}\u{20E3}", 92),
+    ("\u{2A}\u{FE0F}\u{20E3}", 92),
+    ("\u{30}\u{FE0F}\u{20E3}", 92),
+    ("\u{31}\u{FE0F}\u{20E3}", 92),
+    ("\u{32}\u{FE0F}\u{20E3}", 92),
+    ("\u{33}\u{FE0F}\u{20E3}", 92),
+    ("\u{34}\u{FE0F}\u{20E3}", 92),
+    ("\u{35}\u{FE0F}\u{20E3}", 92),
+    ("\u{36}\u{FE0F}\u{20E3}", 92),
+    ("\u{37}\u{FE0F}\u{20E3}", 92),
+    ("\u{38}\u{FE0F}\u{20E3}", 92),
+    ("\u{39}\u{FE0F}\u{20E3}", 92),
+    ("\u{A9}\u{FE0F}", 91),
+    ("\u{AE}\u{FE0F}", 91),
+    ("\u{203C}\u{FE0F}", 89),
+    ("\u{2049}\u{FE0F}", 89),
+    ("\u{2122}\u{FE0F}", 91),
+    ("\u{2139}\u{FE0F}", 93),
+    ("\u{2194}\u{FE0F}", 83),
+    ("\u{2195}\u{FE0F}", 83),
+    ("\u{2196}\u{FE0F}", 83),
+    ("\u{2197}\u{FE0F}", 83),
+    ("\u{2198}\u{FE0F}", 83),
+    ("\u{2199}\u{FE0F}", 83),
+    ("\u{21A9}\u{FE0F}", 83),
+    ("\u{21AA}\u{FE0F}", 83),
+    ("\u{231A}", 56),
+    ("\u{231B}", 56),
+    ("\u{2328}\u{FE0F}", 68),
+    ("\u{23CF}\u{FE0F}", 86),
+    ("\u{23E9}", 86),
+    ("\u{23EA}", 86),
+    ("\u{23EB}", 86),
+    ("\u{23EC}", 86),
+    ("\u{23ED}\u{FE0F}", 86),
+    ("\u{23EE}\u{FE0F}", 86),
+    ("\u{23EF}\u{FE0F}", 86),
+    ("\u{23F0}", 56),
+    ("\u{23F1}\u{FE0F}", 56),
+    ("\u{23F2}\u{FE0F}", 56),
+    ("\u{23F3}", 56),
+    ("\u{23F8}\u{FE0F}", 86),
+    ("\u{23F9}\u{FE0F}", 86),
+    ("\u{23FA}\u{FE0F}", 86),
+    ("\u{24C2}\u{FE0F}", 93),
+    ("\u{25AA}\u{FE0F}", 94),
+    ("\u{25AB}\u{FE0F}", 94),
+    ("\u{25B6}\u{FE0F}", 86),
+    ("\u{25C0}\u{FE0F}", 86),
+    ("\u{25FB}\u{FE0F}", 94),
+    ("\u{25FC}\u{FE0F}", 94),
+    ("\u{25FD}", 94),
+    ("\u{25FE}", 94),
+    ("\u{2600}\u{FE0F}", 57),
+    ("\u{2601}\u{FE0F}", 57),
+    ("\u{2602}\u{FE0F}", 57),
+    ("\u{2603}\u{FE0F}", 57),
+    ("\u{2604}\u{FE0F}", 57),
+    ("\u{260E}\u{FE0F}", 67),
+    ("\u{2611}\u{FE0F}", 91),
+    ("\u{2614}", 57),
+    ("\u{2615}", 45),
+    ("\u{2618}\u{FE0F}", 39),
+    ("\u{261D}\u{FE0F}", 18),
+    ("\u{2620}\u{FE0F}", 10),
+    ("\u{2622}\u{FE0F}", 82),
+    ("\u{2623}\u{FE0F}", 82),
+    ("\u{2626}\u{FE0F}", 84),
+    ("\u{262A}\u{FE0F}", 84),
+    ("\u{262E}\u{FE0F}", 84),
+    ("\u{262F}\u{FE0F}", 84),
+    ("\u{2638}\u{FE0F}", 84),
+    ("\u{2639}\u{FE0F}", 9),
+    ("\u{263A}\u{FE0F}", 1),
+    ("\u{2640}\u{FE0F}", 87),
+    ("\u{2642}\u{FE0F}", 87),
+    ("\u{2648}", 85),
+    ("\u{2649}", 85),
+    ("\u{264A}", 85),
+    ("\u{264B}", 85),
+    ("\u{264C}", 85),
+    ("\u{264D}", 85),
+    ("\u{264E}", 85),
+    ("\u{264F}", 85),
+    ("\u{2650}", 85),
+    ("\u{2651}", 85),
+    ("\u{2652}", 85),
+    ("\u{2653}", 85),
+    ("\u{265F}\u{FE0F}", 61),
+    ("\u{2660}\u{FE0F}", 61),
+    ("\u{2663}\u{FE0F}", 61),
+    ("\u{2665}\u{FE0F}", 61),
+    ("\u{2666}\u{FE0F}", 61),
+    ("\u{2668}\u{FE0F}", 51),
+    ("\u{267B}\u{FE0F}", 91),
+    ("\u{267E}\u{FE0F}", 88),
+    ("\u{267F}", 81),
+    ("\u{2692}\u{FE0F}", 76),
+    ("\u{2693}", 53),
+    ("\u{2694}\u{FE0F}", 76),
+    ("\u{2695}\u{FE0F}", 91),
+    ("\u{2696}\u{FE0F}", 76),
+    ("\u{2697}\u{FE0F}", 77),
+    ("\u{2699}\u{FE0F}", 76),
+    ("\u{269B}\u{FE0F}", 84),
+    ("\u{269C}\u{FE0F}", 91),
+    ("\u{26A0}\u{FE0F}", 82),
+    ("\u{26A1}", 57),
+    ("\u{26A7}\u{FE0F}", 87),
+    ("\u{26AA}", 94),
+    ("\u{26AB}", 94),
+    ("\u{26B0}\u{FE0F}", 80),
+    ("\u{26B1}\u{FE0F}", 80),
+    ("\u{26BD}", 60),
+    ("\u{26BE}", 60),
+    ("\u{26C4}", 57),
+    ("\u{26C5}", 57),
+    ("\u{26C8}\u{FE0F}", 57),
+    ("\u{26CE}", 85),
+    ("\u{26CF}\u{FE0F}", 76),
+    ("\u{26D1}\u{FE0F}", 63),
+    ("\u{26D3}\u{FE0F}", 76),
+    ("\u{26D3}\u{FE0F}\u{200D}\u{1F4A5}", 76),
+    ("\u{26D4}", 82),
+    ("\u{26E9}\u{FE0F}", 50),
+    ("\u{26EA}", 50),
+    ("\u{26F0}\u{FE0F}", 48),
+    ("\u{26F1}\u{FE0F}", 57),
+    ("\u{26F2}", 51),
+    ("\u{26F3}", 60),
+    ("\u{26F4}\u{FE0F}", 53),
+    ("\u{26F5}", 53),
+    ("\u{26F7}\u{FE0F}", 28),
+    ("\u{26F8}\u{FE0F}", 60),
+    ("\u{26F9}\u{FE0F}", 28),
+    ("\u{26F9}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{26F9}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{26FA}", 51),
+    ("\u{26FD}", 52),
+    ("\u{2702}\u{FE0F}", 74),
+    ("\u{2705}", 91),
+    ("\u{2708}\u{FE0F}", 54),
+    ("\u{2709}\u{FE0F}", 72),
+    ("\u{270A}", 19),
+    ("\u{270B}", 16),
+    ("\u{270C}\u{FE0F}", 17),
+    ("\u{270D}\u{FE0F}", 21),
+    ("\u{270F}\u{FE0F}", 73),
+    ("\u{2712}\u{FE0F}", 73),
+    ("\u{2714}\u{FE0F}", 91),
+    ("\u{2716}\u{FE0F}", 88),
+    ("\u{271D}\u{FE0F}", 84),
+    ("\u{2721}\u{FE0F}", 84),
+    ("\u{2728}", 58),
+    ("\u{2733}\u{FE0F}", 91),
+    ("\u{2734}\u{FE0F}", 91),
+    ("\u{2744}\u{FE0F}", 57),
+    ("\u{2747}\u{FE0F}", 91),
+    ("\u{274C}", 91),
+    ("\u{274E}", 91),
+    ("\u{2753}", 89),
+    ("\u{2754}", 89),
+    ("\u{2755}", 89),
+    ("\u{2757}", 89),
+    ("\u{2763}\u{FE0F}", 14),
+    ("\u{2764}\u{FE0F}", 14),
+    ("\u{2764}\u{FE0F}\u{200D}\u{1F525}", 14),
+    ("\u{2764}\u{FE0F}\u{200D}\u{1FA79}", 14),
+    ("\u{2795}", 88),
+    ("\u{2796}", 88),
+    ("\u{2797}", 88),
+    ("\u{27A1}\u{FE0F}", 83),
+    ("\u{27B0}", 91),
+    ("\u{27BF}", 91),
+    ("\u{2934}\u{FE0F}", 83),
+    ("\u{2935}\u{FE0F}", 83),
+    ("\u{2B05}\u{FE0F}", 83),
+    ("\u{2B06}\u{FE0F}", 83),
+    ("\u{2B07}\u{FE0F}", 83),
+    ("\u{2B1B}", 94),
+    ("\u{2B1C}", 94),
+    ("\u{2B50}", 57),
+    ("\u{2B55}", 91),
+    ("\u{3030}\u{FE0F}", 89),
+    ("\u{303D}\u{FE0F}", 91),
+    ("\u{3297}\u{FE0F}", 93),
+    ("\u{3299}\u{FE0F}", 93),
+    ("\u{1F004}", 61),
+    ("\u{1F0CF}", 61),
+    ("\u{1F170}\u{FE0F}", 93),
+    ("\u{1F171}\u{FE0F}", 93),
+    ("\u{1F17E}\u{FE0F}", 93),
+    ("\u{1F17F}\u{FE0F}", 93),
+    ("\u{1F18E}", 93),
+    ("\u{1F191}", 93),
+    ("\u{1F192}", 93),
+    ("\u{1F193}", 93),
+    ("\u{1F194}", 93),
+    ("\u{1F195}", 93),
+    ("\u{1F196}", 93),
+    ("\u{1F197}", 93),
+    ("\u{1F198}", 93),
+    ("\u{1F199}", 93),
+    ("\u{1F19A}", 93),
+    ("\u{1F1E6}\u{1F1E8}", 96),
+    ("\u{1F1E6}\u{1F1E9}", 96),
+    ("\u{1F1E6}\u{1F1EA}", 96),
+    ("\u{1F1E6}\u{1F1EB}", 96),
+    ("\u{1F1E6}\u{1F1EC}", 96),
+    ("\u{1F1E6}\u{1F1EE}", 96),
+    ("\u{1F1E6}\u{1F1F1}", 96),
+    ("\u{1F1E6}\u{1F1F2}", 96),
+    ("\u{1F1E6}\u{1F1F4}", 96),
+    ("\u{1F1E6}\u{1F1F6}", 96),
+    ("\u{1F1E6}\u{1F1F7}", 96),
+    ("\u{1F1E6}\u{1F1F8}", 96),
+    ("\u{1F1E6}\u{1F1F9}", 96),
+    ("\u{1F1E6}\u{1F1FA}", 96),
+    ("\u{1F1E6}\u{1F1FC}", 96),
+    ("\u{1F1E6}\u{1F1FD}", 96),
+    ("\u{1F1E6}\u{1F1FF}", 96),
+    ("\u{1F1E7}\u{1F1E6}", 96),
+    ("\u{1F1E7}\u{1F1E7}", 96),
+    ("\u{1F1E7}\u{1F1E9}", 96),
+    ("\u{1F1E7}\u{1F1EA}", 96),
+    ("\u{1F1E7}\u{1F1EB}", 96),
+    ("\u{1F1E7}\u{1F1EC}", 96),
+    ("\u{1F1E7}\u{1F1ED}", 96),
+    ("\u{1F1E7}\u{1F1EE}", 96),
+    ("\u{1F1E7}\u{1F1EF}", 96),
+    ("\u{1F1E7}\u{1F1F1}", 96),
+    ("\u{1F1E7}\u{1F1F2}", 96),
+    ("\u{1F1E7}\u{1F1F3}", 96),
+    ("\u{1F1E7}\u{1F1F4}", 96),
+    ("\u{1F1E7}\u{1F1F6}", 96),
+    ("\u{1F1E7}\u{1F1F7}", 96),
+    ("\u{1F1E7}\u{1F1F8}", 96),
+    ("\u{1F1E7}\u{1F1F9}", 96),
+    ("\u{1F1E7}\u{1F1FB}", 96),
+    ("\u{1F1E7}\u{1F1FC}", 96),
+    ("\u{1F1E7}\u{1F1FE}", 96),
+    ("\u{1F1E7}\u{1F1FF}", 96),
+    ("\u{1F1E8}\u{1F1E6}", 96),
+    ("\u{1F1E8}\u{1F1E8}", 96),
+    ("\u{1F1E8}\u{1F1E9}", 96),
+    ("\u{1F1E8}\u{1F1EB}", 96),
+    ("\u{1F1E8}\u{1F1EC}", 96),
+    ("\u{1F1E8}\u{1F1ED}", 96),
+    ("\u{1F1E8}\u{1F1EE}", 96),
+    ("\u{1F1E8}\u{1F1F0}", 96),
+    ("\u{1F1E8}\u{1F1F1}", 96),
+    ("\u{1F1E8}\u{1F1F2}", 96),
+    ("\u{1F1E8}\u{1F1F3}", 96),
+    ("\u{1F1E8}\u{1F1F4}", 96),
+    ("\u{1F1E8}\u{1F1F5}", 96),
+    ("\u{1F1E8}\u{1F1F6}", 96),
+    ("\u{1F1E8}\u{1F1F7}", 96),
+    ("\u{1F1E8}\u{1F1FA}", 96),
+    ("\u{1F1E8}\u{1F1FB}", 96),
+    ("\u{1F1E8}\u{1F1FC}", 96),
+    ("\u{1F1E8}\u{1F1FD}", 96),
+    ("\u{1F1E8}\u{1F1FE}", 96),
+    ("\u{1F1E8}\u{1F1FF}", 96),
+    ("\u{1F1E9}\u{1F1EA}", 96),
+    ("\u{1F1E9}\u{1F1EC}", 96),
+    ("\u{1F1E9}\u{1F1EF}", 96),
+    ("\u{1F1E9}\u{1F1F0}", 96),
+    ("\u{1F1E9}\u{1F1F2}", 96),
+    ("\u{1F1E9}\u{1F1F4}", 96),
+    ("\u{1F1E9}\u{1F1FF}", 96),
+    ("\u{1F1EA}\u{1F1E6}", 96),
+    ("\u{1F1EA}\u{1F1E8}", 96),
+    ("\u{1F1EA}\u{1F1EA}", 96),
+    ("\u{1F1EA}\u{1F1EC}", 96),
+    ("\u{1F1EA}\u{1F1ED}", 96),
+    ("\u{1F1EA}\u{1F1F7}", 96),
+    ("\u{1F1EA}\u{1F1F8}", 96),
+    ("\u{1F1EA}\u{1F1F9}", 96),
+    ("\u{1F1EA}\u{1F1FA}", 96),
+    ("\u{1F1EB}\u{1F1EE}", 96),
+    ("\u{1F1EB}\u{1F1EF}", 96),
+    ("\u{1F1EB}\u{1F1F0}", 96),
+    ("\u{1F1EB}\u{1F1F2}", 96),
+    ("\u{1F1EB}\u{1F1F4}", 96),
+    ("\u{1F1EB}\u{1F1F7}", 96),
+    ("\u{1F1EC}\u{1F1E6}", 96),
+    ("\u{1F1EC}\u{1F1E7}", 96),
+    ("\u{1F1EC}\u{1F1E9}", 96),
+    ("\u{1F1EC}\u{1F1EA}", 96),
+    ("\u{1F1EC}\u{1F1EB}", 96),
+    ("\u{1F1EC}\u{1F1EC}", 96),
+    ("\u{1F1EC}\u{1F1ED}", 96),
+    ("\u{1F1EC}\u{1F1EE}", 96),
+    ("\u{1F1EC}\u{1F1F1}", 96),
+    ("\u{1F1EC}\u{1F1F2}", 96),
+    ("\u{1F1EC}\u{1F1F3}", 96),
+    ("\u{1F1EC}\u{1F1F5}", 96),
+    ("\u{1F1EC}\u{1F1F6}", 96),
+    ("\u{1F1EC}\u{1F1F7}", 96),
+    ("\u{1F1EC}\u{1F1F8}", 96),
+    ("\u{1F1EC}\u{1F1F9}", 96),
+    ("\u{1F1EC}\u{1F1FA}", 96),
+    ("\u{1F1EC}\u{1F1FC}", 96),
+    ("\u{1F1EC}\u{1F1FE}", 96),
+    ("\u{1F1ED}\u{1F1F0}", 96),
+    ("\u{1F1ED}\u{1F1F2}", 96),
+    ("\u{1F1ED}\u{1F1F3}", 96),
+    ("\u{1F1ED}\u{1F1F7}", 96),
+    ("\u{1F1ED}\u{1F1F9}", 96),
+    ("\u{1F1ED}\u{1F1FA}", 96),
+    ("\u{1F1EE}\u{1F1E8}", 96),
+    ("\u{1F1EE}\u{1F1E9}", 96),
+    ("\u{1F1EE}\u{1F1EA}", 96),
+    ("\u{1F1EE}\u{1F1F1}", 96),
+    ("\u{1F1EE}\u{1F1F2}", 96),
+    ("\u{1F1EE}\u{1F1F3}", 96),
+    ("\u{1F1EE}\u{1F1F4}", 96),
+    ("\u{1F1EE}\u{1F1F6}", 96),
+    ("\u{1F1EE}\u{1F1F7}", 96),
+    ("\u{1F1EE}\u{1F1F8}", 96),
+    ("\u{1F1EE}\u{1F1F9}", 96),
+    ("\u{1F1EF}\u{1F1EA}", 96),
+    ("\u{1F1EF}\u{1F1F2}", 96),
+    ("\u{1F1EF}\u{1F1F4}", 96),
+    ("\u{1F1EF}\u{1F1F5}", 96),
+    ("\u{1F1F0}\u{1F1EA}", 96),
+    ("\u{1F1F0}\u{1F1EC}", 96),
+    ("\u{1F1F0}\u{1F1ED}", 96),
+    ("\u{1F1F0}\u{1F1EE}", 96),
+    ("\u{1F1F0}\u{1F1F2}", 96),
+    ("\u{1F1F0}\u{1F1F3}", 96),
+    ("\u{1F1F0}\u{1F1F5}", 96),
+    ("\u{1F1F0}\u{1F1F7}", 96),
+    ("\u{1F1F0}\u{1F1FC}", 96),
+    ("\u{1F1F0}\u{1F1FE}", 96),
+    ("\u{1F1F0}\u{1F1FF}", 96),
+    ("\u{1F1F1}\u{1F1E6}", 96),
+    ("\u{1F1F1}\u{1F1E7}", 96),
+    ("\u{1F1F1}\u{1F1E8}", 96),
+    ("\u{1F1F1}\u{1F1EE}", 96),
+    ("\u{1F1F1}\u{1F1F0}", 96),
+    ("\u{1F1F1}\u{1F1F7}", 96),
+    ("\u{1F1F1}\u{1F1F8}", 96),
+    ("\u{1F1F1}\u{1F1F9}", 96),
+    ("\u{1F1F1}\u{1F1FA}", 96),
+    ("\u{1F1F1}\u{1F1FB}", 96),
+    ("\u{1F1F1}\u{1F1FE}", 96),
+    ("\u{1F1F2}\u{1F1E6}", 96),
+    ("\u{1F1F2}\u{1F1E8}", 96),
+    ("\u{1F1F2}\u{1F1E9}", 96),
+    ("\u{1F1F2}\u{1F1EA}", 96),
+    ("\u{1F1F2}\u{1F1EB}", 96),
+    ("\u{1F1F2}\u{1F1EC}", 96),
+    ("\u{1F1F2}\u{1F1ED}", 96),
+    ("\u{1F1F2}\u{1F1F0}", 96),
+    ("\u{1F1F2}\u{1F1F1}", 96),
+    ("\u{1F1F2}\u{1F1F2}", 96),
+    ("\u{1F1F2}\u{1F1F3}", 96),
+    ("\u{1F1F2}\u{1F1F4}", 96),
+    ("\u{1F1F2}\u{1F1F5}", 96),
+    ("\u{1F1F2}\u{1F1F6}", 96),
+    ("\u{1F1F2}\u{1F1F7}", 96),
+    ("\u{1F1F2}\u{1F1F8}", 96),
+    ("\u{1F1F2}\u{1F1F9}", 96),
+    ("\u{1F1F2}\u{1F1FA}", 96),
+    ("\u{1F1F2}\u{1F1FB}", 96),
+    ("\u{1F1F2}\u{1F1FC}", 96),
+    ("\u{1F1F2}\u{1F1FD}", 96),
+    ("\u{1F1F2}\u{1F1FE}", 96),
+    ("\u{1F1F2}\u{1F1FF}", 96),
+    ("\u{1F1F3}\u{1F1E6}", 96),
+    ("\u{1F1F3}\u{1F1E8}", 96),
+    ("\u{1F1F3}\u{1F1EA}", 96),
+    ("\u{1F1F3}\u{1F1EB}", 96),
+    ("\u{1F1F3}\u{1F1EC}", 96),
+    ("\u{1F1F3}\u{1F1EE}", 96),
+    ("\u{1F1F3}\u{1F1F1}", 96),
+    ("\u{1F1F3}\u{1F1F4}", 96),
+    ("\u{1F1F3}\u{1F1F5}", 96),
+    ("\u{1F1F3}\u{1F1F7}", 96),
+    ("\u{1F1F3}\u{1F1FA}", 96),
+    ("\u{1F1F3}\u{1F1FF}", 96),
+    ("\u{1F1F4}\u{1F1F2}", 96),
+    ("\u{1F1F5}\u{1F1E6}", 96),
+    ("\u{1F1F5}\u{1F1EA}", 96),
+    ("\u{1F1F5}\u{1F1EB}", 96),
+    ("\u{1F1F5}\u{1F1EC}", 96),
+    ("\u{1F1F5}\u{1F1ED}", 96),
+    ("\u{1F1F5}\u{1F1F0}", 96),
+    ("\u{1F1F5}\u{1F1F1}", 96),
+    ("\u{1F1F5}\u{1F1F2}", 96),
+    ("\u{1F1F5}\u{1F1F3}", 96),
+    ("\u{1F1F5}\u{1F1F7}", 96),
+    ("\u{1F1F5}\u{1F1F8}", 96),
+    ("\u{1F1F5}\u{1F1F9}", 96),
+    ("\u{1F1F5}\u{1F1FC}", 96),
+    ("\u{1F1F5}\u{1F1FE}", 96),
+    ("\u{1F1F6}\u{1F1E6}", 96),
+    ("\u{1F1F7}\u{1F1EA}", 96),
+    ("\u{1F1F7}\u{1F1F4}", 96),
+    ("\u{1F1F7}\u{1F1F8}", 96),
+    ("\u{1F1F7}\u{1F1FA}", 96),
+    ("\u{1F1F7}\u{1F1FC}", 96),
+    ("\u{1F1F8}\u{1F1E6}", 96),
+    ("\u{1F1F8}\u{1F1E7}", 96),
+    ("\u{1F1F8}\u{1F1E8}", 96),
+    ("\u{1F1F8}\u{1F1E9}", 96),
+    ("\u{1F1F8}\u{1F1EA}", 96),
+    ("\u{1F1F8}\u{1F1EC}", 96),
+    ("\u{1F1F8}\u{1F1ED}", 96),
+    ("\u{1F1F8}\u{1F1EE}", 96),
+    ("\u{1F1F8}\u{1F1EF}", 96),
+    ("\u{1F1F8}\u{1F1F0}", 96),
+    ("\u{1F1F8}\u{1F1F1}", 96),
+    ("\u{1F1F8}\u{1F1F2}", 96),
+    ("\u{1F1F8}\u{1F1F3}", 96),
+    ("\u{1F1F8}\u{1F1F4}", 96),
+    ("\u{1F1F8}\u{1F1F7}", 96),
+    ("\u{1F1F8}\u{1F1F8}", 96),
+    ("\u{1F1F8}\u{1F1F9}", 96),
+    ("\u{1F1F8}\u{1F1FB}", 96),
+    ("\u{1F1F8}\u{1F1FD}", 96),
+    ("\u{1F1F8}\u{1F1FE}", 96),
+    ("\u{1F1F8}\u{1F1FF}", 96),
+    ("\u{1F1F9}\u{1F1E6}", 96),
+    ("\u{1F1F9}\u{1F1E8}", 96),
+    ("\u{1F1F9}\u{1F1E9}", 96),
+    ("\u{1F1F9}\u{1F1EB}", 96),
+    ("\u{1F1F9}\u{1F1EC}", 96),
+    ("\u{1F1F9}\u{1F1ED}", 96),
+    ("\u{1F1F9}\u{1F1EF}", 96),
+    ("\u{1F1F9}\u{1F1F0}", 96),
+    ("\u{1F1F9}\u{1F1F1}", 96),
+    ("\u{1F1F9}\u{1F1F2}", 96),
+    ("\u{1F1F9}\u{1F1F3}", 96),
+    ("\u{1F1F9}\u{1F1F4}", 96),
+    ("\u{1F1F9}\u{1F1F7}", 96),
+    ("\u{1F1F9}\u{1F1F9}", 96),
+    ("\u{1F1F9}\u{1F1FB}", 96),
+    ("\u{1F1F9}\u{1F1FC}", 96),
+    ("\u{1F1F9}\u{1F1FF}", 96),
+    ("\u{1F1FA}\u{1F1E6}", 96),
+    ("\u{1F1FA}\u{1F1EC}", 96),
+    ("\u{1F1FA}\u{1F1F2}", 96),
+    ("\u{1F1FA}\u{1F1F3}", 96),
+    ("\u{1F1FA}\u{1F1F8}", 96),
+    ("\u{1F1FA}\u{1F1FE}", 96),
+    ("\u{1F1FA}\u{1F1FF}", 96),
+    ("\u{1F1FB}\u{1F1E6}", 96),
+    ("\u{1F1FB}\u{1F1E8}", 96),
+    ("\u{1F1FB}\u{1F1EA}", 96),
+    ("\u{1F1FB}\u{1F1EC}", 96),
+    ("\u{1F1FB}\u{1F1EE}", 96),
+    ("\u{1F1FB}\u{1F1F3}", 96),
+    ("\u{1F1FB}\u{1F1FA}", 96),
+    ("\u{1F1FC}\u{1F1EB}", 96),
+    ("\u{1F1FC}\u{1F1F8}", 96),
+    ("\u{1F1FD}\u{1F1F0}", 96),
+    ("\u{1F1FE}\u{1F1EA}", 96),
+    ("\u{1F1FE}\u{1F1F9}", 96),
+    ("\u{1F1FF}\u{1F1E6}", 96),
+    ("\u{1F1FF}\u{1F1F2}", 96),
+    ("\u{1F1FF}\u{1F1FC}", 96),
+    ("\u{1F201}", 93),
+    ("\u{1F202}\u{FE0F}", 93),
+    ("\u{1F21A}", 93),
+    ("\u{1F22F}", 93),
+    ("\u{1F232}", 93),
+    ("\u{1F233}", 93),
+    ("\u{1F234}", 93),
+    ("\u{1F235}", 93),
+    ("\u{1F236}", 93),
+    ("\u{1F237}\u{FE0F}", 93),
+    ("\u{1F238}", 93),
+    ("\u{1F239}", 93),
+    ("\u{1F23A}", 93),
+    ("\u{1F250}", 93),
+    ("\u{1F251}", 93),
+    ("\u{1F300}", 57),
+    ("\u{1F301}", 51),
+    ("\u{1F302}", 57),
+    ("\u{1F303}", 51),
+    ("\u{1F304}", 51),
+    ("\u{1F305}", 51),
+    ("\u{1F306}", 51),
+    ("\u{1F307}", 51),
+    ("\u{1F308}", 57),
+    ("\u{1F309}", 51),
+    ("\u{1F30A}", 57),
+    ("\u{1F30B}", 48),
+    ("\u{1F30C}", 57),
+    ("\u{1F30D}", 47),
+    ("\u{1F30E}", 47),
+    ("\u{1F30F}", 47),
+    ("\u{1F310}", 47),
+    ("\u{1F311}", 57),
+    ("\u{1F312}", 57),
+    ("\u{1F313}", 57),
+    ("\u{1F314}", 57),
+    ("\u{1F315}", 57),
+    ("\u{1F316}", 57),
+    ("\u{1F317}", 57),
+    ("\u{1F318}", 57),
+    ("\u{1F319}", 57),
+    ("\u{1F31A}", 57),
+    ("\u{1F31B}", 57),
+    ("\u{1F31C}", 57),
+    ("\u{1F31D}", 57),
+    ("\u{1F31E}", 57),
+    ("\u{1F31F}", 57),
+    ("\u{1F320}", 57),
+    ("\u{1F321}\u{FE0F}", 57),
+    ("\u{1F324}\u{FE0F}", 57),
+    ("\u{1F325}\u{FE0F}", 57),
+    ("\u{1F326}\u{FE0F}", 57),
+    ("\u{1F327}\u{FE0F}", 57),
+    ("\u{1F328}\u{FE0F}", 57),
+    ("\u{1F329}\u{FE0F}", 57),
+    ("\u{1F32A}\u{FE0F}", 57),
+    ("\u{1F32B}\u{FE0F}", 57),
+    ("\u{1F32C}\u{FE0F}", 57),
+    ("\u{1F32D}", 42),
+    ("\u{1F32E}", 42),
+    ("\u{1F32F}", 42),
+    ("\u{1F330}", 41),
+    ("\u{1F331}", 39),
+    ("\u{1F332}", 39),
+    ("\u{1F333}", 39),
+    ("\u{1F334}", 39),
+    ("\u{1F335}", 39),
+    ("\u{1F336}\u{FE0F}", 41),
+    ("\u{1F337}", 38),
+    ("\u{1F338}", 38),
+    ("\u{1F339}", 38),
+    ("\u{1F33A}", 38),
+    ("\u{1F33B}", 38),
+    ("\u{1F33C}", 38),
+    ("\u{1F33D}", 41),
+    ("\u{1F33E}", 39),
+    ("\u{1F33F}", 39),
+    ("\u{1F340}", 39),
+    ("\u{1F341}", 39),
+    ("\u{1F342}", 39),
+    ("\u{1F343}", 39),
+    ("\u{1F344}", 39),
+    ("\u{1F344}\u{200D}\u{1F7EB}", 41),
+    ("\u{1F345}", 40),
+    ("\u{1F346}", 41),
+    ("\u{1F347}", 40),
+    ("\u{1F348}", 40),
+    ("\u{1F349}", 40),
+    ("\u{1F34A}", 40),
+    ("\u{1F34B}", 40),
+    ("\u{1F34B}\u{200D}\u{1F7E9}", 40),
+    ("\u{1F34C}", 40),
+    ("\u{1F34D}", 40),
+    ("\u{1F34E}", 40),
+    ("\u{1F34F}", 40),
+    ("\u{1F350}", 40),
+    ("\u{1F351}", 40),
+    ("\u{1F352}", 40),
+    ("\u{1F353}", 40),
+    ("\u{1F354}", 42),
+    ("\u{1F355}", 42),
+    ("\u{1F356}", 42),
+    ("\u{1F357}", 42),
+    ("\u{1F358}", 43),
+    ("\u{1F359}", 43),
+    ("\u{1F35A}", 43),
+    ("\u{1F35B}", 43),
+    ("\u{1F35C}", 43),
+    ("\u{1F35D}", 43),
+    ("\u{1F35E}", 42),
+    ("\u{1F35F}", 42),
+    ("\u{1F360}", 43),
+    ("\u{1F361}", 43),
+    ("\u{1F362}", 43),
+    ("\u{1F363}", 43),
+    ("\u{1F364}", 43),
+    ("\u{1F365}", 43),
+    ("\u{1F366}", 44),
+    ("\u{1F367}", 44),
+    ("\u{1F368}", 44),
+    ("\u{1F369}", 44),
+    ("\u{1F36A}", 44),
+    ("\u{1F36B}", 44),
+    ("\u{1F36C}", 44),
+    ("\u{1F36D}", 44),
+    ("\u{1F36E}", 44),
+    ("\u{1F36F}", 44),
+    ("\u{1F370}", 44),
+    ("\u{1F371}", 43),
+    ("\u{1F372}", 42),
+    ("\u{1F373}", 42),
+    ("\u{1F374}", 46),
+    ("\u{1F375}", 45),
+    ("\u{1F376}", 45),
+    ("\u{1F377}", 45),
+    ("\u{1F378}", 45),
+    ("\u{1F379}", 45),
+    ("\u{1F37A}", 45),
+    ("\u{1F37B}", 45),
+    ("\u{1F37C}", 45),
+    ("\u{1F37D}\u{FE0F}", 46),
+    ("\u{1F37E}", 45),
+    ("\u{1F37F}", 42),
+    ("\u{1F380}", 58),
+    ("\u{1F381}", 58),
+    ("\u{1F382}", 44),
+    ("\u{1F383}", 58),
+    ("\u{1F384}", 58),
+    ("\u{1F385}", 26),
+    ("\u{1F386}", 58),
+    ("\u{1F387}", 58),
+    ("\u{1F388}", 58),
+    ("\u{1F389}", 58),
+    ("\u{1F38A}", 58),
+    ("\u{1F38B}", 58),
+    ("\u{1F38C}", 95),
+    ("\u{1F38D}", 58),
+    ("\u{1F38E}", 58),
+    ("\u{1F38F}", 58),
+    ("\u{1F390}", 58),
+    ("\u{1F391}", 58),
+    ("\u{1F392}", 63),
+    ("\u{1F393}", 63),
+    ("\u{1F396}\u{FE0F}", 59),
+    ("\u{1F397}\u{FE0F}", 58),
+    ("\u{1F399}\u{FE0F}", 65),
+    ("\u{1F39A}\u{FE0F}", 65),
+    ("\u{1F39B}\u{FE0F}", 65),
+    ("\u{1F39E}\u{FE0F}", 69),
+    ("\u{1F39F}\u{FE0F}", 58),
+    ("\u{1F3A0}", 51),
+    ("\u{1F3A1}", 51),
+    ("\u{1F3A2}", 51),
+    ("\u{1F3A3}", 60),
+    ("\u{1F3A4}", 65),
+    ("\u{1F3A5}", 69),
+    ("\u{1F3A6}", 86),
+    ("\u{1F3A7}", 65),
+    ("\u{1F3A8}", 62),
+    ("\u{1F3A9}", 63),
+    ("\u{1F3AA}", 51),
+    ("\u{1F3AB}", 58),
+    ("\u{1F3AC}", 69),
+    ("\u{1F3AD}", 62),
+    ("\u{1F3AE}", 61),
+    ("\u{1F3AF}", 61),
+    ("\u{1F3B0}", 61),
+    ("\u{1F3B1}", 61),
+    ("\u{1F3B2}", 61),
+    ("\u{1F3B3}", 60),
+    ("\u{1F3B4}", 61),
+    ("\u{1F3B5}", 65),
+    ("\u{1F3B6}", 65),
+    ("\u{1F3B7}", 66),
+    ("\u{1F3B8}", 66),
+    ("\u{1F3B9}", 66),
+    ("\u{1F3BA}", 66),
+    ("\u{1F3BB}", 66),
+    ("\u{1F3BC}", 65),
+    ("\u{1F3BD}", 60),
+    ("\u{1F3BE}", 60),
+    ("\u{1F3BF}", 60),
+    ("\u{1F3C0}", 60),
+    ("\u{1F3C1}", 95),
+    ("\u{1F3C2}", 28),
+    ("\u{1F3C3}", 27),
+    ("\u{1F3C3}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F3C3}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F3C3}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F3C3}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F3C3}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F3C4}", 28),
+    ("\u{1F3C4}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F3C4}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F3C5}", 59),
+    ("\u{1F3C6}", 59),
+    ("\u{1F3C7}", 28),
+    ("\u{1F3C8}", 60),
+    ("\u{1F3C9}", 60),
+    ("\u{1F3CA}", 28),
+    ("\u{1F3CA}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F3CA}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F3CB}\u{FE0F}", 28),
+    ("\u{1F3CB}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F3CB}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F3CC}\u{FE0F}", 28),
+    ("\u{1F3CC}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F3CC}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F3CD}\u{FE0F}", 52),
+    ("\u{1F3CE}\u{FE0F}", 52),
+    ("\u{1F3CF}", 60),
+    ("\u{1F3D0}", 60),
+    ("\u{1F3D1}", 60),
+    ("\u{1F3D2}", 60),
+    ("\u{1F3D3}", 60),
+    ("\u{1F3D4}\u{FE0F}", 48),
+    ("\u{1F3D5}\u{FE0F}", 48),
+    ("\u{1F3D6}\u{FE0F}", 48),
+    ("\u{1F3D7}\u{FE0F}", 49),
+    ("\u{1F3D8}\u{FE0F}", 49),
+    ("\u{1F3D9}\u{FE0F}", 51),
+    ("\u{1F3DA}\u{FE0F}", 49),
+    ("\u{1F3DB}\u{FE0F}", 49),
+    ("\u{1F3DC}\u{FE0F}", 48),
+    ("\u{1F3DD}\u{FE0F}", 48),
+    ("\u{1F3DE}\u{FE0F}", 48),
+    ("\u{1F3DF}\u{FE0F}", 49),
+    ("\u{1F3E0}", 49),
+    ("\u{1F3E1}", 49),
+    ("\u{1F3E2}", 49),
+    ("\u{1F3E3}", 49),
+    ("\u{1F3E4}", 49),
+    ("\u{1F3E5}", 49),
+    ("\u{1F3E6}", 49),
+    ("\u{1F3E7}", 81),
+    ("\u{1F3E8}", 49),
+    ("\u{1F3E9}", 49),
+    ("\u{1F3EA}", 49),
+    ("\u{1F3EB}", 49),
+    ("\u{1F3EC}", 49),
+    ("\u{1F3ED}", 49),
+    ("\u{1F3EE}", 69),
+    ("\u{1F3EF}", 49),
+    ("\u{1F3F0}", 49),
+    ("\u{1F3F3}\u{FE0F}", 95),
+    ("\u{1F3F3}\u{FE0F}\u{200D}\u{26A7}\u{FE0F}", 95),
+    ("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", 95),
+    ("\u{1F3F4}", 95),
+    ("\u{1F3F4}\u{200D}\u{2620}\u{FE0F}", 95),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}", 97),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}", 97),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}", 97),
+    ("\u{1F3F5}\u{FE0F}", 38),
+    ("\u{1F3F7}\u{FE0F}", 70),
+    ("\u{1F3F8}", 60),
+    ("\u{1F3F9}", 76),
+    ("\u{1F3FA}", 46),
+    ("\u{1F400}", 32),
+    ("\u{1F401}", 32),
+    ("\u{1F402}", 32),
+    ("\u{1F403}", 32),
+    ("\u{1F404}", 32),
+    ("\u{1F405}", 32),
+    ("\u{1F406}", 32),
+    ("\u{1F407}", 32),
+    ("\u{1F408}", 32),
+    ("\u{1F408}\u{200D}\u{2B1B}", 32),
+    ("\u{1F409}", 35),
+    ("\u{1F40A}", 35),
+    ("\u{1F40B}", 36),
+    ("\u{1F40C}", 37),
+    ("\u{1F40D}", 35),
+    ("\u{1F40E}", 32),
+    ("\u{1F40F}", 32),
+    ("\u{1F410}", 32),
+    ("\u{1F411}", 32),
+    ("\u{1F412}", 32),
+    ("\u{1F413}", 33),
+    ("\u{1F414}", 33),
+    ("\u{1F415}", 32),
+    ("\u{1F415}\u{200D}\u{1F9BA}", 32),
+    ("\u{1F416}", 32),
+    ("\u{1F417}", 32),
+    ("\u{1F418}", 32),
+    ("\u{1F419}", 36),
+    ("\u{1F41A}", 36),
+    ("\u{1F41B}", 37),
+    ("\u{1F41C}", 37),
+    ("\u{1F41D}", 37),
+    ("\u{1F41E}", 37),
+    ("\u{1F41F}", 36),
+    ("\u{1F420}", 36),
+    ("\u{1F421}", 36),
+    ("\u{1F422}", 35),
+    ("\u{1F423}", 33),
+    ("\u{1F424}", 33),
+    ("\u{1F425}", 33),
+    ("\u{1F426}", 33),
+    ("\u{1F426}\u{200D}\u{2B1B}", 33),
+    ("\u{1F426}\u{200D}\u{1F525}", 33),
+    ("\u{1F427}", 33),
+    ("\u{1F428}", 32),
+    ("\u{1F429}", 32),
+    ("\u{1F42A}", 32),
+    ("\u{1F42B}", 32),
+    ("\u{1F42C}", 36),
+    ("\u{1F42D}", 32),
+    ("\u{1F42E}", 32),
+    ("\u{1F42F}", 32),
+    ("\u{1F430}", 32),
+    ("\u{1F431}", 32),
+    ("\u{1F432}", 35),
+    ("\u{1F433}", 36),
+    ("\u{1F434}", 32),
+    ("\u{1F435}", 32),
+    ("\u{1F436}", 32),
+    ("\u{1F437}", 32),
+    ("\u{1F438}", 34),
+    ("\u{1F439}", 32),
+    ("\u{1F43A}", 32),
+    ("\u{1F43B}", 32),
+    ("\u{1F43B}\u{200D}\u{2744}\u{FE0F}", 32),
+    ("\u{1F43C}", 32),
+    ("\u{1F43D}", 32),
+    ("\u{1F43E}", 32),
+    ("\u{1F43F}\u{FE0F}", 32),
+    ("\u{1F440}", 22),
+    ("\u{1F441}\u{FE0F}", 22),
+    ("\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}", 15),
+    ("\u{1F442}", 22),
+    ("\u{1F443}", 22),
+    ("\u{1F444}", 22),
+    ("\u{1F445}", 22),
+    ("\u{1F446}", 18),
+    ("\u{1F447}", 18),
+    ("\u{1F448}", 18),
+    ("\u{1F449}", 18),
+    ("\u{1F44A}", 19),
+    ("\u{1F44B}", 16),
+    ("\u{1F44C}", 17),
+    ("\u{1F44D}", 19),
+    ("\u{1F44E}", 19),
+    ("\u{1F44F}", 20),
+    ("\u{1F450}", 20),
+    ("\u{1F451}", 63),
+    ("\u{1F452}", 63),
+    ("\u{1F453}", 63),
+    ("\u{1F454}", 63),
+    ("\u{1F455}", 63),
+    ("\u{1F456}", 63),
+    ("\u{1F457}", 63),
+    ("\u{1F458}", 63),
+    ("\u{1F459}", 63),
+    ("\u{1F45A}", 63),
+    ("\u{1F45B}", 63),
+    ("\u{1F45C}", 63),
+    ("\u{1F45D}", 63),
+    ("\u{1F45E}", 63),
+    ("\u{1F45F}", 63),
+    ("\u{1F460}", 63),
+    ("\u{1F461}", 63),
+    ("\u{1F462}", 63),
+    ("\u{1F463}", 31),
+    ("\u{1F464}", 31),
+    ("\u{1F465}", 31),
+    ("\u{1F466}", 23),
+    ("\u{1F467}", 23),
+    ("\u{1F468}", 23),
+    ("\u{1F468}\u{200D}\u{2695}\u{FE0F}", 25),
+    ("\u{1F468}\u{200D}\u{2696}\u{FE0F}", 25),
+    ("\u{1F468}\u{200D}\u{2708}\u{FE0F}", 25),
+    ("\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}", 30),
+    ("\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}", 30),
+    ("\u{1F468}\u{200D}\u{1F33E}", 25),
+    ("\u{1F468}\u{200D}\u{1F373}", 25),
+    ("\u{1F468}\u{200D}\u{1F37C}", 25),
+    ("\u{1F468}\u{200D}\u{1F393}", 25),
+    ("\u{1F468}\u{200D}\u{1F3A4}", 25),
+    ("\u{1F468}\u{200D}\u{1F3A8}", 25),
+    ("\u{1F468}\u{200D}\u{1F3EB}", 25),
+    ("\u{1F468}\u{200D}\u{1F3ED}", 25),
+    ("\u{1F468}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F467}", 30),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", 30),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}", 30),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", 30),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 30),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", 30),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", 30),
+    ("\u{1F468}\u{200D}\u{1F4BB}", 25),
+    ("\u{1F468}\u{200D}\u{1F4BC}", 25),
+    ("\u{1F468}\u{200D}\u{1F527}", 25),
+    ("\u{1F468}\u{200D}\u{1F52C}", 25),
+    ("\u{1F468}\u{200D}\u{1F680}", 25),
+    ("\u{1F468}\u{200D}\u{1F692}", 25),
+    ("\u{1F468}\u{200D}\u{1F9AF}", 27),
+    ("\u{1F468}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F468}\u{200D}\u{1F9B0}", 23),
+    ("\u{1F468}\u{200D}\u{1F9B1}", 23),
+    ("\u{1F468}\u{200D}\u{1F9B2}", 23),
+    ("\u{1F468}\u{200D}\u{1F9B3}", 23),
+    ("\u{1F468}\u{200D}\u{1F9BC}", 27),
+    ("\u{1F468}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F468}\u{200D}\u{1F9BD}", 27),
+    ("\u{1F468}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F469}", 23),
+    ("\u{1F469}\u{200D}\u{2695}\u{FE0F}", 25),
+    ("\u{1F469}\u{200D}\u{2696}\u{FE0F}", 25),
+    ("\u{1F469}\u{200D}\u{2708}\u{FE0F}", 25),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}", 30),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}", 30),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}", 30),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}", 30),
+    ("\u{1F469}\u{200D}\u{1F33E}", 25),
+    ("\u{1F469}\u{200D}\u{1F373}", 25),
+    ("\u{1F469}\u{200D}\u{1F37C}", 25),
+    ("\u{1F469}\u{200D}\u{1F393}", 25),
+    ("\u{1F469}\u{200D}\u{1F3A4}", 25),
+    ("\u{1F469}\u{200D}\u{1F3A8}", 25),
+    ("\u{1F469}\u{200D}\u{1F3EB}", 25),
+    ("\u{1F469}\u{200D}\u{1F3ED}", 25),
+    ("\u{1F469}\u{200D}\u{1F466}", 30),
+    ("\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", 30),
+    ("\u{1F469}\u{200D}\u{1F467}", 30),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", 30),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", 30),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}", 30),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", 30),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}", 30),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", 30),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", 30),
+    ("\u{1F469}\u{200D}\u{1F4BB}", 25),
+    ("\u{1F469}\u{200D}\u{1F4BC}", 25),
+    ("\u{1F469}\u{200D}\u{1F527}", 25),
+    ("\u{1F469}\u{200D}\u{1F52C}", 25),
+    ("\u{1F469}\u{200D}\u{1F680}", 25),
+    ("\u{1F469}\u{200D}\u{1F692}", 25),
+    ("\u{1F469}\u{200D}\u{1F9AF}", 27),
+    ("\u{1F469}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F469}\u{200D}\u{1F9B0}", 23),
+    ("\u{1F469}\u{200D}\u{1F9B1}", 23),
+    ("\u{1F469}\u{200D}\u{1F9B2}", 23),
+    ("\u{1F469}\u{200D}\u{1F9B3}", 23),
+    ("\u{1F469}\u{200D}\u{1F9BC}", 27),
+    ("\u{1F469}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F469}\u{200D}\u{1F9BD}", 27),
+    ("\u{1F469}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F46A}", 31),
+    ("\u{1F46B}", 30),
+    ("\u{1F46C}", 30),
+    ("\u{1F46D}", 30),
+    ("\u{1F46E}", 25),
+    ("\u{1F46E}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F46E}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F46F}", 27),
+    ("\u{1F46F}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F46F}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F470}", 25),
+    ("\u{1F470}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F470}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F471}", 23),
+    ("\u{1F471}\u{200D}\u{2640}\u{FE0F}", 23),
+    ("\u{1F471}\u{200D}\u{2642}\u{FE0F}", 23),
+    ("\u{1F472}", 25),
+    ("\u{1F473}", 25),
+    ("\u{1F473}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F473}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F474}", 23),
+    ("\u{1F475}", 23),
+    ("\u{1F476}", 23),
+    ("\u{1F477}", 25),
+    ("\u{1F477}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F477}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F478}", 25),
+    ("\u{1F479}", 11),
+    ("\u{1F47A}", 11),
+    ("\u{1F47B}", 11),
+    ("\u{1F47C}", 26),
+    ("\u{1F47D}", 11),
+    ("\u{1F47E}", 11),
+    ("\u{1F47F}", 10),
+    ("\u{1F480}", 10),
+    ("\u{1F481}", 24),
+    ("\u{1F481}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F481}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F482}", 25),
+    ("\u{1F482}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F482}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F483}", 27),
+    ("\u{1F484}", 63),
+    ("\u{1F485}", 21),
+    ("\u{1F486}", 27),
+    ("\u{1F486}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F486}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F487}", 27),
+    ("\u{1F487}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F487}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F488}", 51),
+    ("\u{1F489}", 78),
+    ("\u{1F48A}", 78),
+    ("\u{1F48B}", 15),
+    ("\u{1F48C}", 14),
+    ("\u{1F48D}", 63),
+    ("\u{1F48E}", 63),
+    ("\u{1F48F}", 30),
+    ("\u{1F490}", 38),
+    ("\u{1F491}", 30),
+    ("\u{1F492}", 49),
+    ("\u{1F493}", 14),
+    ("\u{1F494}", 14),
+    ("\u{1F495}", 14),
+    ("\u{1F496}", 14),
+    ("\u{1F497}", 14),
+    ("\u{1F498}", 14),
+    ("\u{1F499}", 14),
+    ("\u{1F49A}", 14),
+    ("\u{1F49B}", 14),
+    ("\u{1F49C}", 14),
+    ("\u{1F49D}", 14),
+    ("\u{1F49E}", 14),
+    ("\u{1F49F}", 14),
+    ("\u{1F4A0}", 94),
+    ("\u{1F4A1}", 69),
+    ("\u{1F4A2}", 15),
+    ("\u{1F4A3}", 76),
+    ("\u{1F4A4}", 15),
+    ("\u{1F4A5}", 15),
+    ("\u{1F4A6}", 15),
+    ("\u{1F4A7}", 57),
+    ("\u{1F4A8}", 15),
+    ("\u{1F4A9}", 11),
+    ("\u{1F4AA}", 22),
+    ("\u{1F4AB}", 15),
+    ("\u{1F4AC}", 15),
+    ("\u{1F4AD}", 15),
+    ("\u{1F4AE}", 38),
+    ("\u{1F4AF}", 15),
+    ("\u{1F4B0}", 71),
+    ("\u{1F4B1}", 90),
+    ("\u{1F4B2}", 90),
+    ("\u{1F4B3}", 71),
+    ("\u{1F4B4}", 71),
+    ("\u{1F4B5}", 71),
+    ("\u{1F4B6}", 71),
+    ("\u{1F4B7}", 71),
+    ("\u{1F4B8}", 71),
+    ("\u{1F4B9}", 71),
+    ("\u{1F4BA}", 54),
+    ("\u{1F4BB}", 68),
+    ("\u{1F4BC}", 74),
+    ("\u{1F4BD}", 68),
+    ("\u{1F4BE}", 68),
+    ("\u{1F4BF}", 68),
+    ("\u{1F4C0}", 68),
+    ("\u{1F4C1}", 74),
+    ("\u{1F4C2}", 74),
+    ("\u{1F4C3}", 70),
+    ("\u{1F4C4}", 70),
+    ("\u{1F4C5}", 74),
+    ("\u{1F4C6}", 74),
+    ("\u{1F4C7}", 74),
+    ("\u{1F4C8}", 74),
+    ("\u{1F4C9}", 74),
+    ("\u{1F4CA}", 74),
+    ("\u{1F4CB}", 74),
+    ("\u{1F4CC}", 74),
+    ("\u{1F4CD}", 74),
+    ("\u{1F4CE}", 74),
+    ("\u{1F4CF}", 74),
+    ("\u{1F4D0}", 74),
+    ("\u{1F4D1}", 70),
+    ("\u{1F4D2}", 70),
+    ("\u{1F4D3}", 70),
+    ("\u{1F4D4}", 70),
+    ("\u{1F4D5}", 70),
+    ("\u{1F4D6}", 70),
+    ("\u{1F4D7}", 70),
+    ("\u{1F4D8}", 70),
+    ("\u{1F4D9}", 70),
+    ("\u{1F4DA}", 70),
+    ("\u{1F4DB}", 91),
+    ("\u{1F4DC}", 70),
+    ("\u{1F4DD}", 73),
+    ("\u{1F4DE}", 67),
+    ("\u{1F4DF}", 67),
+    ("\u{1F4E0}", 67),
+    ("\u{1F4E1}", 77),
+    ("\u{1F4E2}", 64),
+    ("\u{1F4E3}", 64),
+    ("\u{1F4E4}", 72),
+    ("\u{1F4E5}", 72),
+    ("\u{1F4E6}", 72),
+    ("\u{1F4E7}", 72),
+    ("\u{1F4E8}", 72),
+    ("\u{1F4E9}", 72),
+    ("\u{1F4EA}", 72),
+    ("\u{1F4EB}", 72),
+    ("\u{1F4EC}", 72),
+    ("\u{1F4ED}", 72),
+    ("\u{1F4EE}", 72),
+    ("\u{1F4EF}", 64),
+    ("\u{1F4F0}", 70),
+    ("\u{1F4F1}", 67),
+    ("\u{1F4F2}", 67),
+    ("\u{1F4F3}", 86),
+    ("\u{1F4F4}", 86),
+    ("\u{1F4F5}", 82),
+    ("\u{1F4F6}", 86),
+    ("\u{1F4F7}", 69),
+    ("\u{1F4F8}", 69),
+    ("\u{1F4F9}", 69),
+    ("\u{1F4FA}", 69),
+    ("\u{1F4FB}", 65),
+    ("\u{1F4FC}", 69),
+    ("\u{1F4FD}\u{FE0F}", 69),
+    ("\u{1F4FF}", 63),
+    ("\u{1F500}", 86),
+    ("\u{1F501}", 86),
+    ("\u{1F502}", 86),
+    ("\u{1F503}", 83),
+    ("\u{1F504}", 83),
+    ("\u{1F505}", 86),
+    ("\u{1F506}", 86),
+    ("\u{1F507}", 64),
+    ("\u{1F508}", 64),
+    ("\u{1F509}", 64),
+    ("\u{1F50A}", 64),
+    ("\u{1F50B}", 68),
+    ("\u{1F50C}", 68),
+    ("\u{1F50D}", 69),
+    ("\u{1F50E}", 69),
+    ("\u{1F50F}", 75),
+    ("\u{1F510}", 75),
+    ("\u{1F511}", 75),
+    ("\u{1F512}", 75),
+    ("\u{1F513}", 75),
+    ("\u{1F514}", 64),
+    ("\u{1F515}", 64),
+    ("\u{1F516}", 70),
+    ("\u{1F517}", 76),
+    ("\u{1F518}", 94),
+    ("\u{1F519}", 83),
+    ("\u{1F51A}", 83),
+    ("\u{1F51B}", 83),
+    ("\u{1F51C}", 83),
+    ("\u{1F51D}", 83),
+    ("\u{1F51E}", 82),
+    ("\u{1F51F}", 92),
+    ("\u{1F520}", 93),
+    ("\u{1F521}", 93),
+    ("\u{1F522}", 93),
+    ("\u{1F523}", 93),
+    ("\u{1F524}", 93),
+    ("\u{1F525}", 57),
+    ("\u{1F526}", 69),
+    ("\u{1F527}", 76),
+    ("\u{1F528}", 76),
+    ("\u{1F529}", 76),
+    ("\u{1F52A}", 46),
+    ("\u{1F52B}", 61),
+    ("\u{1F52C}", 77),
+    ("\u{1F52D}", 77),
+    ("\u{1F52E}", 61),
+    ("\u{1F52F}", 84),
+    ("\u{1F530}", 91),
+    ("\u{1F531}", 91),
+    ("\u{1F532}", 94),
+    ("\u{1F533}", 94),
+    ("\u{1F534}", 94),
+    ("\u{1F535}", 94),
+    ("\u{1F536}", 94),
+    ("\u{1F537}", 94),
+    ("\u{1F538}", 94),
+    ("\u{1F539}", 94),
+    ("\u{1F53A}", 94),
+    ("\u{1F53B}", 94),
+    ("\u{1F53C}", 86),
+    ("\u{1F53D}", 86),
+    ("\u{1F549}\u{FE0F}", 84),
+    ("\u{1F54A}\u{FE0F}", 33),
+    ("\u{1F54B}", 50),
+    ("\u{1F54C}", 50),
+    ("\u{1F54D}", 50),
+    ("\u{1F54E}", 84),
+    ("\u{1F550}", 56),
+    ("\u{1F551}", 56),
+    ("\u{1F552}", 56),
+    ("\u{1F553}", 56),
+    ("\u{1F554}", 56),
+    ("\u{1F555}", 56),
+    ("\u{1F556}", 56),
+    ("\u{1F557}", 56),
+    ("\u{1F558}", 56),
+    ("\u{1F559}", 56),
+    ("\u{1F55A}", 56),
+    ("\u{1F55B}", 56),
+    ("\u{1F55C}", 56),
+    ("\u{1F55D}", 56),
+    ("\u{1F55E}", 56),
+    ("\u{1F55F}", 56),
+    ("\u{1F560}", 56),
+    ("\u{1F561}", 56),
+    ("\u{1F562}", 56),
+    ("\u{1F563}", 56),
+    ("\u{1F564}", 56),
+    ("\u{1F565}", 56),
+    ("\u{1F566}", 56),
+    ("\u{1F567}", 56),
+    ("\u{1F56F}\u{FE0F}", 69),
+    ("\u{1F570}\u{FE0F}", 56),
+    ("\u{1F573}\u{FE0F}", 15),
+    ("\u{1F574}\u{FE0F}", 27),
+    ("\u{1F575}\u{FE0F}", 25),
+    ("\u{1F575}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F575}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F576}\u{FE0F}", 63),
+    ("\u{1F577}\u{FE0F}", 37),
+    ("\u{1F578}\u{FE0F}", 37),
+    ("\u{1F579}\u{FE0F}", 61),
+    ("\u{1F57A}", 27),
+    ("\u{1F587}\u{FE0F}", 74),
+    ("\u{1F58A}\u{FE0F}", 73),
+    ("\u{1F58B}\u{FE0F}", 73),
+    ("\u{1F58C}\u{FE0F}", 73),
+    ("\u{1F58D}\u{FE0F}", 73),
+    ("\u{1F590}\u{FE0F}", 16),
+    ("\u{1F595}", 18),
+    ("\u{1F596}", 16),
+    ("\u{1F5A4}", 14),
+    ("\u{1F5A5}\u{FE0F}", 68),
+    ("\u{1F5A8}\u{FE0F}", 68),
+    ("\u{1F5B1}\u{FE0F}", 68),
+    ("\u{1F5B2}\u{FE0F}", 68),
+    ("\u{1F5BC}\u{FE0F}", 62),
+    ("\u{1F5C2}\u{FE0F}", 74),
+    ("\u{1F5C3}\u{FE0F}", 74),
+    ("\u{1F5C4}\u{FE0F}", 74),
+    ("\u{1F5D1}\u{FE0F}", 74),
+    ("\u{1F5D2}\u{FE0F}", 74),
+    ("\u{1F5D3}\u{FE0F}", 74),
+    ("\u{1F5DC}\u{FE0F}", 76),
+    ("\u{1F5DD}\u{FE0F}", 75),
+    ("\u{1F5DE}\u{FE0F}", 70),
+    ("\u{1F5E1}\u{FE0F}", 76),
+    ("\u{1F5E3}\u{FE0F}", 31),
+    ("\u{1F5E8}\u{FE0F}", 15),
+    ("\u{1F5EF}\u{FE0F}", 15),
+    ("\u{1F5F3}\u{FE0F}", 72),
+    ("\u{1F5FA}\u{FE0F}", 47),
+    ("\u{1F5FB}", 48),
+    ("\u{1F5FC}", 49),
+    ("\u{1F5FD}", 49),
+    ("\u{1F5FE}", 47),
+    ("\u{1F5FF}", 80),
+    ("\u{1F600}", 0),
+    ("\u{1F601}", 0),
+    ("\u{1F602}", 0),
+    ("\u{1F603}", 0),
+    ("\u{1F604}", 0),
+    ("\u{1F605}", 0),
+    ("\u{1F606}", 0),
+    ("\u{1F607}", 0),
+    ("\u{1F608}", 10),
+    ("\u{1F609}", 0),
+    ("\u{1F60A}", 0),
+    ("\u{1F60B}", 2),
+    ("\u{1F60C}", 5),
+    ("\u{1F60D}", 1),
+    ("\u{1F60E}", 8),
+    ("\u{1F60F}", 4),
+    ("\u{1F610}", 4),
+    ("\u{1F611}", 4),
+    ("\u{1F612}", 4),
+    ("\u{1F613}", 9),
+    ("\u{1F614}", 5),
+    ("\u{1F615}", 9),
+    ("\u{1F616}", 9),
+    ("\u{1F617}", 1),
+    ("\u{1F618}", 1),
+    ("\u{1F619}", 1),
+    ("\u{1F61A}", 1),
+    ("\u{1F61B}", 2),
+    ("\u{1F61C}", 2),
+    ("\u{1F61D}", 2),
+    ("\u{1F61E}", 9),
+    ("\u{1F61F}", 9),
+    ("\u{1F620}", 10),
+    ("\u{1F621}", 10),
+    ("\u{1F622}", 9),
+    ("\u{1F623}", 9),
+    ("\u{1F624}", 10),
+    ("\u{1F625}", 9),
+    ("\u{1F626}", 9),
+    ("\u{1F627}", 9),
+    ("\u{1F628}", 9),
+    ("\u{1F629}", 9),
+    ("\u{1F62A}", 5),
+    ("\u{1F62B}", 9),
+    ("\u{1F62C}", 4),
+    ("\u{1F62D}", 9),
+    ("\u{1F62E}", 9),
+    ("\u{1F62E}\u{200D}\u{1F4A8}", 4),
+    ("\u{1F62F}", 9),
+    ("\u{1F630}", 9),
+    ("\u{1F631}", 9),
+    ("\u{1F632}", 9),
+    ("\u{1F633}", 9),
+    ("\u{1F634}", 5),
+    ("\u{1F635}", 6),
+    ("\u{1F635}\u{200D}\u{1F4AB}", 6),
+    ("\u{1F636}", 4),
+    ("\u{1F636}\u{200D}\u{1F32B}\u{FE0F}", 4),
+    ("\u{1F637}", 6),
+    ("\u{1F638}", 12),
+    ("\u{1F639}", 12),
+    ("\u{1F63A}", 12),
+    ("\u{1F63B}", 12),
+    ("\u{1F63C}", 12),
+    ("\u{1F63D}", 12),
+    ("\u{1F63E}", 12),
+    ("\u{1F63F}", 12),
+    ("\u{1F640}", 12),
+    ("\u{1F641}", 9),
+    ("\u{1F642}", 0),
+    ("\u{1F642}\u{200D}\u{2194}\u{FE0F}", 4),
+    ("\u{1F642}\u{200D}\u{2195}\u{FE0F}", 4),
+    ("\u{1F643}", 0),
+    ("\u{1F644}", 4),
+    ("\u{1F645}", 24),
+    ("\u{1F645}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F645}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F646}", 24),
+    ("\u{1F646}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F646}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F647}", 24),
+    ("\u{1F647}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F647}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F648}", 13),
+    ("\u{1F649}", 13),
+    ("\u{1F64A}", 13),
+    ("\u{1F64B}", 24),
+    ("\u{1F64B}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F64B}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F64C}", 20),
+    ("\u{1F64D}", 24),
+    ("\u{1F64D}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F64D}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F64E}", 24),
+    ("\u{1F64E}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F64E}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F64F}", 20),
+    ("\u{1F680}", 54),
+    ("\u{1F681}", 54),
+    ("\u{1F682}", 52),
+    ("\u{1F683}", 52),
+    ("\u{1F684}", 52),
+    ("\u{1F685}", 52),
+    ("\u{1F686}", 52),
+    ("\u{1F687}", 52),
+    ("\u{1F688}", 52),
+    ("\u{1F689}", 52),
+    ("\u{1F68A}", 52),
+    ("\u{1F68B}", 52),
+    ("\u{1F68C}", 52),
+    ("\u{1F68D}", 52),
+    ("\u{1F68E}", 52),
+    ("\u{1F68F}", 52),
+    ("\u{1F690}", 52),
+    ("\u{1F691}", 52),
+    ("\u{1F692}", 52),
+    ("\u{1F693}", 52),
+    ("\u{1F694}", 52),
+    ("\u{1F695}", 52),
+    ("\u{1F696}", 52),
+    ("\u{1F697}", 52),
+    ("\u{1F698}", 52),
+    ("\u{1F699}", 52),
+    ("\u{1F69A}", 52),
+    ("\u{1F69B}", 52),
+    ("\u{1F69C}", 52),
+    ("\u{1F69D}", 52),
+    ("\u{1F69E}", 52),
+    ("\u{1F69F}", 54),
+    ("\u{1F6A0}", 54),
+    ("\u{1F6A1}", 54),
+    ("\u{1F6A2}", 53),
+    ("\u{1F6A3}", 28),
+    ("\u{1F6A3}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F6A3}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F6A4}", 53),
+    ("\u{1F6A5}", 52),
+    ("\u{1F6A6}", 52),
+    ("\u{1F6A7}", 52),
+    ("\u{1F6A8}", 52),
+    ("\u{1F6A9}", 95),
+    ("\u{1F6AA}", 79),
+    ("\u{1F6AB}", 82),
+    ("\u{1F6AC}", 80),
+    ("\u{1F6AD}", 82),
+    ("\u{1F6AE}", 81),
+    ("\u{1F6AF}", 82),
+    ("\u{1F6B0}", 81),
+    ("\u{1F6B1}", 82),
+    ("\u{1F6B2}", 52),
+    ("\u{1F6B3}", 82),
+    ("\u{1F6B4}", 28),
+    ("\u{1F6B4}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F6B4}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F6B5}", 28),
+    ("\u{1F6B5}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F6B5}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F6B6}", 27),
+    ("\u{1F6B6}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F6B6}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F6B6}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F6B6}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F6B6}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F6B7}", 82),
+    ("\u{1F6B8}", 82),
+    ("\u{1F6B9}", 81),
+    ("\u{1F6BA}", 81),
+    ("\u{1F6BB}", 81),
+    ("\u{1F6BC}", 81),
+    ("\u{1F6BD}", 79),
+    ("\u{1F6BE}", 81),
+    ("\u{1F6BF}", 79),
+    ("\u{1F6C0}", 29),
+    ("\u{1F6C1}", 79),
+    ("\u{1F6C2}", 81),
+    ("\u{1F6C3}", 81),
+    ("\u{1F6C4}", 81),
+    ("\u{1F6C5}", 81),
+    ("\u{1F6CB}\u{FE0F}", 79),
+    ("\u{1F6CC}", 29),
+    ("\u{1F6CD}\u{FE0F}", 63),
+    ("\u{1F6CE}\u{FE0F}", 55),
+    ("\u{1F6CF}\u{FE0F}", 79),
+    ("\u{1F6D0}", 84),
+    ("\u{1F6D1}", 52),
+    ("\u{1F6D2}", 79),
+    ("\u{1F6D5}", 50),
+    ("\u{1F6D6}", 49),
+    ("\u{1F6D7}", 79),
+    ("\u{1F6DC}", 86),
+    ("\u{1F6DD}", 51),
+    ("\u{1F6DE}", 52),
+    ("\u{1F6DF}", 53),
+    ("\u{1F6E0}\u{FE0F}", 76),
+    ("\u{1F6E1}\u{FE0F}", 76),
+    ("\u{1F6E2}\u{FE0F}", 52),
+    ("\u{1F6E3}\u{FE0F}", 52),
+    ("\u{1F6E4}\u{FE0F}", 52),
+    ("\u{1F6E5}\u{FE0F}", 53),
+    ("\u{1F6E9}\u{FE0F}", 54),
+    ("\u{1F6EB}", 54),
+    ("\u{1F6EC}", 54),
+    ("\u{1F6F0}\u{FE0F}", 54),
+    ("\u{1F6F3}\u{FE0F}", 53),
+    ("\u{1F6F4}", 52),
+    ("\u{1F6F5}", 52),
+    ("\u{1F6F6}", 53),
+    ("\u{1F6F7}", 60),
+    ("\u{1F6F8}", 54),
+    ("\u{1F6F9}", 52),
+    ("\u{1F6FA}", 52),
+    ("\u{1F6FB}", 52),
+    ("\u{1F6FC}", 52),
+    ("\u{1F7E0}", 94),
+    ("\u{1F7E1}", 94),
+    ("\u{1F7E2}", 94),
+    ("\u{1F7E3}", 94),
+    ("\u{1F7E4}", 94),
+    ("\u{1F7E5}", 94),
+    ("\u{1F7E6}", 94),
+    ("\u{1F7E7}", 94),
+    ("\u{1F7E8}", 94),
+    ("\u{1F7E9}", 94),
+    ("\u{1F7EA}", 94),
+    ("\u{1F7EB}", 94),
+    ("\u{1F7F0}", 88),
+    ("\u{1F90C}", 17),
+    ("\u{1F90D}", 14),
+    ("\u{1F90E}", 14),
+    ("\u{1F90F}", 17),
+    ("\u{1F910}", 4),
+    ("\u{1F911}", 2),
+    ("\u{1F912}", 6),
+    ("\u{1F913}", 8),
+    ("\u{1F914}", 3),
+    ("\u{1F915}", 6),
+    ("\u{1F916}", 11),
+    ("\u{1F917}", 3),
+    ("\u{1F918}", 17),
+    ("\u{1F919}", 17),
+    ("\u{1F91A}", 16),
+    ("\u{1F91B}", 19),
+    ("\u{1F91C}", 19),
+    ("\u{1F91D}", 20),
+    ("\u{1F91E}", 17),
+    ("\u{1F91F}", 17),
+    ("\u{1F920}", 7),
+    ("\u{1F921}", 11),
+    ("\u{1F922}", 6),
+    ("\u{1F923}", 0),
+    ("\u{1F924}", 5),
+    ("\u{1F925}", 4),
+    ("\u{1F926}", 24),
+    ("\u{1F926}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F926}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F927}", 6),
+    ("\u{1F928}", 4),
+    ("\u{1F929}", 1),
+    ("\u{1F92A}", 2),
+    ("\u{1F92B}", 3),
+    ("\u{1F92C}", 10),
+    ("\u{1F92D}", 3),
+    ("\u{1F92E}", 6),
+    ("\u{1F92F}", 6),
+    ("\u{1F930}", 25),
+    ("\u{1F931}", 25),
+    ("\u{1F932}", 20),
+    ("\u{1F933}", 21),
+    ("\u{1F934}", 25),
+    ("\u{1F935}", 25),
+    ("\u{1F935}\u{200D}\u{2640}\u{FE0F}", 25),
+    ("\u{1F935}\u{200D}\u{2642}\u{FE0F}", 25),
+    ("\u{1F936}", 26),
+    ("\u{1F937}", 24),
+    ("\u{1F937}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F937}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F938}", 28),
+    ("\u{1F938}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F938}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F939}", 28),
+    ("\u{1F939}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F939}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F93A}", 28),
+    ("\u{1F93C}", 28),
+    ("\u{1F93C}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F93C}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F93D}", 28),
+    ("\u{1F93D}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F93D}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F93E}", 28),
+    ("\u{1F93E}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F93E}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F93F}", 60),
+    ("\u{1F940}", 38),
+    ("\u{1F941}", 66),
+    ("\u{1F942}", 45),
+    ("\u{1F943}", 45),
+    ("\u{1F944}", 46),
+    ("\u{1F945}", 60),
+    ("\u{1F947}", 59),
+    ("\u{1F948}", 59),
+    ("\u{1F949}", 59),
+    ("\u{1F94A}", 60),
+    ("\u{1F94B}", 60),
+    ("\u{1F94C}", 60),
+    ("\u{1F94D}", 60),
+    ("\u{1F94E}", 60),
+    ("\u{1F94F}", 60),
+    ("\u{1F950}", 42),
+    ("\u{1F951}", 41),
+    ("\u{1F952}", 41),
+    ("\u{1F953}", 42),
+    ("\u{1F954}", 41),
+    ("\u{1F955}", 41),
+    ("\u{1F956}", 42),
+    ("\u{1F957}", 42),
+    ("\u{1F958}", 42),
+    ("\u{1F959}", 42),
+    ("\u{1F95A}", 42),
+    ("\u{1F95B}", 45),
+    ("\u{1F95C}", 41),
+    ("\u{1F95D}", 40),
+    ("\u{1F95E}", 42),
+    ("\u{1F95F}", 43),
+    ("\u{1F960}", 43),
+    ("\u{1F961}", 43),
+    ("\u{1F962}", 46),
+    ("\u{1F963}", 42),
+    ("\u{1F964}", 45),
+    ("\u{1F965}", 40),
+    ("\u{1F966}", 41),
+    ("\u{1F967}", 44),
+    ("\u{1F968}", 42),
+    ("\u{1F969}", 42),
+    ("\u{1F96A}", 42),
+    ("\u{1F96B}", 42),
+    ("\u{1F96C}", 41),
+    ("\u{1F96D}", 40),
+    ("\u{1F96E}", 43),
+    ("\u{1F96F}", 42),
+    ("\u{1F970}", 1),
+    ("\u{1F971}", 9),
+    ("\u{1F972}", 1),
+    ("\u{1F973}", 7),
+    ("\u{1F974}", 6),
+    ("\u{1F975}", 6),
+    ("\u{1F976}", 6),
+    ("\u{1F977}", 25),
+    ("\u{1F978}", 7),
+    ("\u{1F979}", 9),
+    ("\u{1F97A}", 9),
+    ("\u{1F97B}", 63),
+    ("\u{1F97C}", 63),
+    ("\u{1F97D}", 63),
+    ("\u{1F97E}", 63),
+    ("\u{1F97F}", 63),
+    ("\u{1F980}", 36),
+    ("\u{1F981}", 32),
+    ("\u{1F982}", 37),
+    ("\u{1F983}", 33),
+    ("\u{1F984}", 32),
+    ("\u{1F985}", 33),
+    ("\u{1F986}", 33),
+    ("\u{1F987}", 32),
+    ("\u{1F988}", 36),
+    ("\u{1F989}", 33),
+    ("\u{1F98A}", 32),
+    ("\u{1F98B}", 37),
+    ("\u{1F98C}", 32),
+    ("\u{1F98D}", 32),
+    ("\u{1F98E}", 35),
+    ("\u{1F98F}", 32),
+    ("\u{1F990}", 36),
+    ("\u{1F991}", 36),
+    ("\u{1F992}", 32),
+    ("\u{1F993}", 32),
+    ("\u{1F994}", 32),
+    ("\u{1F995}", 35),
+    ("\u{1F996}", 35),
+    ("\u{1F997}", 37),
+    ("\u{1F998}", 32),
+    ("\u{1F999}", 32),
+    ("\u{1F99A}", 33),
+    ("\u{1F99B}", 32),
+    ("\u{1F99C}", 33),
+    ("\u{1F99D}", 32),
+    ("\u{1F99E}", 36),
+    ("\u{1F99F}", 37),
+    ("\u{1F9A0}", 37),
+    ("\u{1F9A1}", 32),
+    ("\u{1F9A2}", 33),
+    ("\u{1F9A3}", 32),
+    ("\u{1F9A4}", 33),
+    ("\u{1F9A5}", 32),
+    ("\u{1F9A6}", 32),
+    ("\u{1F9A7}", 32),
+    ("\u{1F9A8}", 32),
+    ("\u{1F9A9}", 33),
+    ("\u{1F9AA}", 36),
+    ("\u{1F9AB}", 32),
+    ("\u{1F9AC}", 32),
+    ("\u{1F9AD}", 36),
+    ("\u{1F9AE}", 32),
+    ("\u{1F9AF}", 76),
+    ("\u{1F9B4}", 22),
+    ("\u{1F9B5}", 22),
+    ("\u{1F9B6}", 22),
+    ("\u{1F9B7}", 22),
+    ("\u{1F9B8}", 26),
+    ("\u{1F9B8}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9B8}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9B9}", 26),
+    ("\u{1F9B9}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9B9}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9BA}", 63),
+    ("\u{1F9BB}", 22),
+    ("\u{1F9BC}", 52),
+    ("\u{1F9BD}", 52),
+    ("\u{1F9BE}", 22),
+    ("\u{1F9BF}", 22),
+    ("\u{1F9C0}", 42),
+    ("\u{1F9C1}", 44),
+    ("\u{1F9C2}", 42),
+    ("\u{1F9C3}", 45),
+    ("\u{1F9C4}", 41),
+    ("\u{1F9C5}", 41),
+    ("\u{1F9C6}", 42),
+    ("\u{1F9C7}", 42),
+    ("\u{1F9C8}", 42),
+    ("\u{1F9C9}", 45),
+    ("\u{1F9CA}", 45),
+    ("\u{1F9CB}", 45),
+    ("\u{1F9CC}", 26),
+    ("\u{1F9CD}", 27),
+    ("\u{1F9CD}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F9CD}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F9CE}", 27),
+    ("\u{1F9CE}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F9CE}\u{200D}\u{2640}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F9CE}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F9CE}\u{200D}\u{2642}\u{FE0F}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F9CE}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F9CF}", 24),
+    ("\u{1F9CF}\u{200D}\u{2640}\u{FE0F}", 24),
+    ("\u{1F9CF}\u{200D}\u{2642}\u{FE0F}", 24),
+    ("\u{1F9D0}", 8),
+    ("\u{1F9D1}", 23),
+    ("\u{1F9D1}\u{200D}\u{2695}\u{FE0F}", 25),
+    ("\u{1F9D1}\u{200D}\u{2696}\u{FE0F}", 25),
+    ("\u{1F9D1}\u{200D}\u{2708}\u{FE0F}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F33E}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F373}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F37C}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F384}", 26),
+    ("\u{1F9D1}\u{200D}\u{1F393}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F3A4}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F3A8}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F3EB}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F3ED}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F4BB}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F4BC}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F527}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F52C}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F680}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F692}", 25),
+    ("\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}", 30),
+    ("\u{1F9D1}\u{200D}\u{1F9AF}", 27),
+    ("\u{1F9D1}\u{200D}\u{1F9AF}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F9D1}\u{200D}\u{1F9B0}", 23),
+    ("\u{1F9D1}\u{200D}\u{1F9B1}", 23),
+    ("\u{1F9D1}\u{200D}\u{1F9B2}", 23),
+    ("\u{1F9D1}\u{200D}\u{1F9B3}", 23),
+    ("\u{1F9D1}\u{200D}\u{1F9BC}", 27),
+    ("\u{1F9D1}\u{200D}\u{1F9BC}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F9D1}\u{200D}\u{1F9BD}", 27),
+    ("\u{1F9D1}\u{200D}\u{1F9BD}\u{200D}\u{27A1}\u{FE0F}", 27),
+    ("\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}", 31),
+    ("\u{1F9D1}\u{200D}\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}", 31),
+    ("\u{1F9D1}\u{200D}\u{1F9D2}", 31),
+    ("\u{1F9D1}\u{200D}\u{1F9D2}\u{200D}\u{1F9D2}", 31),
+    ("\u{1F9D2}", 23),
+    ("\u{1F9D3}", 23),
+    ("\u{1F9D4}", 23),
+    ("\u{1F9D4}\u{200D}\u{2640}\u{FE0F}", 23),
+    ("\u{1F9D4}\u{200D}\u{2642}\u{FE0F}", 23),
+    ("\u{1F9D5}", 25),
+    ("\u{1F9D6}", 27),
+    ("\u{1F9D6}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F9D6}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F9D7}", 27),
+    ("\u{1F9D7}\u{200D}\u{2640}\u{FE0F}", 27),
+    ("\u{1F9D7}\u{200D}\u{2642}\u{FE0F}", 27),
+    ("\u{1F9D8}", 28),
+    ("\u{1F9D8}\u{200D}\u{2640}\u{FE0F}", 28),
+    ("\u{1F9D8}\u{200D}\u{2642}\u{FE0F}", 28),
+    ("\u{1F9D9}", 26),
+    ("\u{1F9D9}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9D9}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9DA}", 26),
+    ("\u{1F9DA}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9DA}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9DB}", 26),
+    ("\u{1F9DB}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9DB}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9DC}", 26),
+    ("\u{1F9DC}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9DC}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9DD}", 26),
+    ("\u{1F9DD}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9DD}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9DE}", 26),
+    ("\u{1F9DE}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9DE}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9DF}", 26),
+    ("\u{1F9DF}\u{200D}\u{2640}\u{FE0F}", 26),
+    ("\u{1F9DF}\u{200D}\u{2642}\u{FE0F}", 26),
+    ("\u{1F9E0}", 22),
+    ("\u{1F9E1}", 14),
+    ("\u{1F9E2}", 63),
+    ("\u{1F9E3}", 63),
+    ("\u{1F9E4}", 63),
+    ("\u{1F9E5}", 63),
+    ("\u{1F9E6}", 63),
+    ("\u{1F9E7}", 58),
+    ("\u{1F9E8}", 58),
+    ("\u{1F9E9}", 61),
+    ("\u{1F9EA}", 77),
+    ("\u{1F9EB}", 77),
+    ("\u{1F9EC}", 77),
+    ("\u{1F9ED}", 47),
+    ("\u{1F9EE}", 68),
+    ("\u{1F9EF}", 79),
+    ("\u{1F9F0}", 76),
+    ("\u{1F9F1}", 49),
+    ("\u{1F9F2}", 76),
+    ("\u{1F9F3}", 55),
+    ("\u{1F9F4}", 79),
+    ("\u{1F9F5}", 62),
+    ("\u{1F9F6}", 62),
+    ("\u{1F9F7}", 79),
+    ("\u{1F9F8}", 61),
+    ("\u{1F9F9}", 79),
+    ("\u{1F9FA}", 79),
+    ("\u{1F9FB}", 79),
+    ("\u{1F9FC}", 79),
+    ("\u{1F9FD}", 79),
+    ("\u{1F9FE}", 71),
+    ("\u{1F9FF}", 80),
+    ("\u{1FA70}", 63),
+    ("\u{1FA71}", 63),
+    ("\u{1FA72}", 63),
+    ("\u{1FA73}", 63),
+    ("\u{1FA74}", 63),
+    ("\u{1FA75}", 14),
+    ("\u{1FA76}", 14),
+    ("\u{1FA77}", 14),
+    ("\u{1FA78}", 78),
+    ("\u{1FA79}", 78),
+    ("\u{1FA7A}", 78),
+    ("\u{1FA7B}", 78),
+    ("\u{1FA7C}", 78),
+    ("\u{1FA80}", 61),
+    ("\u{1FA81}", 61),
+    ("\u{1FA82}", 54),
+    ("\u{1FA83}", 76),
+    ("\u{1FA84}", 61),
+    ("\u{1FA85}", 61),
+    ("\u{1FA86}", 61),
+    ("\u{1FA87}", 66),
+    ("\u{1FA88}", 66),
+    ("\u{1FA89}", 66),
+    ("\u{1FA8F}", 76),
+    ("\u{1FA90}", 57),
+    ("\u{1FA91}", 79),
+    ("\u{1FA92}", 79),
+    ("\u{1FA93}", 76),
+    ("\u{1FA94}", 69),
+    ("\u{1FA95}", 66),
+    ("\u{1FA96}", 63),
+    ("\u{1FA97}", 66),
+    ("\u{1FA98}", 66),
+    ("\u{1FA99}", 71),
+    ("\u{1FA9A}", 76),
+    ("\u{1FA9B}", 76),
+    ("\u{1FA9C}", 76),
+    ("\u{1FA9D}", 76),
+    ("\u{1FA9E}", 79),
+    ("\u{1FA9F}", 79),
+    ("\u{1FAA0}", 79),
+    ("\u{1FAA1}", 62),
+    ("\u{1FAA2}", 62),
+    ("\u{1FAA3}", 79),
+    ("\u{1FAA4}", 79),
+    ("\u{1FAA5}", 79),
+    ("\u{1FAA6}", 80),
+    ("\u{1FAA7}", 80),
+    ("\u{1FAA8}", 49),
+    ("\u{1FAA9}", 61),
+    ("\u{1FAAA}", 80),
+    ("\u{1FAAB}", 68),
+    ("\u{1FAAC}", 80),
+    ("\u{1FAAD}", 63),
+    ("\u{1FAAE}", 63),
+    ("\u{1FAAF}", 84),
+    ("\u{1FAB0}", 37),
+    ("\u{1FAB1}", 37),
+    ("\u{1FAB2}", 37),
+    ("\u{1FAB3}", 37),
+    ("\u{1FAB4}", 39),
+    ("\u{1FAB5}", 49),
+    ("\u{1FAB6}", 33),
+    ("\u{1FAB7}", 38),
+    ("\u{1FAB8}", 36),
+    ("\u{1FAB9}", 39),
+    ("\u{1FABA}", 39),
+    ("\u{1FABB}", 38),
+    ("\u{1FABC}", 36),
+    ("\u{1FABD}", 33),
+    ("\u{1FABE}", 39),
+    ("\u{1FABF}", 33),
+    ("\u{1FAC0}", 22),
+    ("\u{1FAC1}", 22),
+    ("\u{1FAC2}", 31),
+    ("\u{1FAC3}", 25),
+    ("\u{1FAC4}", 25),
+    ("\u{1FAC5}", 25),
+    ("\u{1FAC6}", 31),
+    ("\u{1FACE}", 32),
+    ("\u{1FACF}", 32),
+    ("\u{1FAD0}", 40),
+    ("\u{1FAD1}", 41),
+    ("\u{1FAD2}", 40),
+    ("\u{1FAD3}", 42),
+    ("\u{1FAD4}", 42),
+    ("\u{1FAD5}", 42),
+    ("\u{1FAD6}", 45),
+    ("\u{1FAD7}", 45),
+    ("\u{1FAD8}", 41),
+    ("\u{1FAD9}", 46),
+    ("\u{1FADA}", 41),
+    ("\u{1FADB}", 41),
+    ("\u{1FADC}", 41),
+    ("\u{1FADF}", 91),
+    ("\u{1FAE0}", 0),
+    ("\u{1FAE1}", 3),
+    ("\u{1FAE2}", 3),
+    ("\u{1FAE3}", 3),
+    ("\u{1FAE4}", 9),
+    ("\u{1FAE5}", 4),
+    ("\u{1FAE6}", 22),
+    ("\u{1FAE7}", 79),
+    ("\u{1FAE8}", 4),
+    ("\u{1FAE9}", 5),
+    ("\u{1FAF0}", 17),
+    ("\u{1FAF1}", 16),
+    ("\u{1FAF2}", 16),
+    ("\u{1FAF3}", 16),
+    ("\u{1FAF4}", 16),
+    ("\u{1FAF5}", 18),
+    ("\u{1FAF6}", 20),
+    ("\u{1FAF7}", 16),
+    ("\u{1FAF8}", 16),
+];
@@ -0,0 +1,138 @@
+//! Which files are processed, and how, based on their name or extension.
+
+use std::{collections::HashMap, fmt, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::Syntax;
+
+/// How files of one type are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Processor {
+    Markdown,
+    Plain,
+    /// Never picked up by directory scans.
+    Ignore,
+}
+
+impl Processor {
+    pub fn syntax(self) -> Option<Syntax> {
+        match self {
+            Processor::Markdown => Some(Syntax::Markdown),
+            Processor::Plain => Some(Syntax::Plain),
+            Processor::Ignore => None,
+        }
+    }
+}
+
+impl FromStr for Processor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(Processor::Markdown),
+            "plain" | "text" => Ok(Processor::Plain),
+            "ignore" => Ok(Processor::Ignore),
+            _ => Err(format!(
+                "unknown processor `{}` (expected `markdown`, `plain` or `ignore`)",
+                s
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for Processor {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Processor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Processor::Markdown => "markdown",
+            Processor::Plain => "plain",
+            Processor::Ignore => "ignore",
+        })
+    }
+}
+
+/// A `<pattern>=<processor>` mapping such as `*.rst=plain` or `LICENSE=plain`.
+///
+/// Patterns starting with `*.` match an extension; anything else matches a
+/// whole file name. Both are compared case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub pattern: String,
+    pub processor: Processor,
+}
+
+impl FromStr for Mapping {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pattern, processor) = s
+            .split_once('=')
+            .ok_or_else(|| format!("invalid file type mapping `{}` (expected `<pattern>=<processor>`)", s))?;
+        if pattern.is_empty() {
+            return Err(format!("invalid file type mapping `{}`: empty pattern", s));
+        }
+        Ok(Mapping {
+            pattern: pattern.to_string(),
+            processor: processor.parse()?,
+        })
+    }
+}
+
+/// Registry from extensions and file names to processors.
+#[derive(Debug, Clone)]
+pub struct FileTypes {
+    extensions: HashMap<String, Processor>,
+    names: HashMap<String, Processor>,
+}
+
+impl Default for FileTypes {
+    fn default() -> Self {
+        let mut types = Self {
+            extensions: HashMap::new(),
+            names: HashMap::new(),
+        };
+        for extension in ["md", "markdown", "mdown", "mkd", "mkdn", "mdwn", "mdx"] {
+            types.extensions.insert(extension.to_string(), Processor::Markdown);
+        }
+        for extension in ["txt", "text"] {
+            types.extensions.insert(extension.to_string(), Processor::Plain);
+        }
+        // Extensionless READMEs are usually Markdown, and treating them as such
+        // keeps indented code samples intact either way.
+        types.names.insert("readme".to_string(), Processor::Markdown);
+        types
+    }
+}
+
+impl FileTypes {
+    pub fn insert(&mut self, pattern: &str, processor: Processor) {
+        match pattern.strip_prefix("*.") {
+            Some(extension) => self.extensions.insert(extension.to_lowercase(), processor),
+            None => self.names.insert(pattern.to_lowercase(), processor),
+        };
+    }
+
+    pub fn processor(&self, path: &Path) -> Option<Processor> {
+        let name = path.file_name()?.to_str()?.to_lowercase();
+        if let Some(processor) = self.names.get(&name) {
+            return Some(*processor);
+        }
+
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        self.extensions.get(&extension).copied()
+    }
+
+    /// The syntax `path` is processed with, or `None` if it is not picked up.
+    pub fn syntax(&self, path: &Path) -> Option<Syntax> {
+        self.processor(path).and_then(Processor::syntax)
+    }
+}
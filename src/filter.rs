@@ -0,0 +1,170 @@
+//! Allow and deny lists deciding which emoji sequences are stripped.
+
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use emojis::Group;
+use serde::Deserialize;
+
+use crate::emoji;
+
+const GROUPS: [(Group, &str); 9] = [
+    (Group::SmileysAndEmotion, "Smileys & Emotion"),
+    (Group::PeopleAndBody, "People & Body"),
+    (Group::AnimalsAndNature, "Animals & Nature"),
+    (Group::FoodAndDrink, "Food & Drink"),
+    (Group::TravelAndPlaces, "Travel & Places"),
+    (Group::Activities, "Activities"),
+    (Group::Objects, "Objects"),
+    (Group::Symbols, "Symbols"),
+    (Group::Flags, "Flags"),
+];
+
+/// CLDR display name of an emoji group, e.g. "Smileys & Emotion".
+pub fn group_name(group: Group) -> &'static str {
+    GROUPS.iter().find(|(g, _)| *g == group).map_or("", |(_, name)| name)
+}
+
+/// Lowercase with every run of non-alphanumerics dropped, so "Smileys & Emotion",
+/// "smileys-and-emotion" and "SmileysAndEmotion" all compare equal; used for
+/// subgroups too.
+fn normalize_group(name: &str) -> String {
+    name.to_lowercase()
+        .replace('&', "and")
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// A set of emoji sequences in an allow or deny list.
+///
+/// Written as the sequence itself (`✅`), a codepoint or codepoint range
+/// (`U+2705`, `U+1F300..U+1F5FF`) matched against the sequence's first
+/// codepoint, a CLDR group (`group:Flags`, `group:Smileys & Emotion`) or a
+/// CLDR subgroup (`subgroup:face-smiling`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Pattern {
+    Sequence(String),
+    Codepoints(RangeInclusive<char>),
+    Group(Group),
+    Subgroup(String),
+}
+
+impl Pattern {
+    /// Higher is more specific; on a tie between lists, deny wins.
+    fn specificity(&self) -> u8 {
+        match self {
+            Pattern::Sequence(_) => 3,
+            Pattern::Codepoints(_) => 2,
+            Pattern::Subgroup(_) => 1,
+            Pattern::Group(_) => 0,
+        }
+    }
+
+    pub fn matches(&self, sequence: &str) -> bool {
+        match self {
+            Pattern::Sequence(s) => {
+                emoji::without_variation_selectors(s) == emoji::without_variation_selectors(sequence)
+            }
+            Pattern::Codepoints(range) => sequence.chars().next().is_some_and(|c| range.contains(&c)),
+            Pattern::Group(group) => emoji::lookup(sequence).is_some_and(|e| e.group() == *group),
+            Pattern::Subgroup(subgroup) => emoji::subgroup(sequence) == Some(subgroup.as_str()),
+        }
+    }
+}
+
+fn parse_codepoint(s: &str) -> Option<char> {
+    let hex = s.strip_prefix("U+").or_else(|| s.strip_prefix("u+"))?;
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+impl FromStr for Pattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix("group:") {
+            let wanted = normalize_group(name);
+            return GROUPS
+                .iter()
+                .find(|(_, n)| normalize_group(n) == wanted)
+                .map(|(group, _)| Pattern::Group(*group))
+                .ok_or_else(|| {
+                    let names: Vec<_> = GROUPS.iter().map(|(_, n)| *n).collect();
+                    format!("unknown emoji group `{}` (expected one of: {})", name, names.join(", "))
+                });
+        }
+
+        if let Some(name) = s.strip_prefix("subgroup:") {
+            let wanted = normalize_group(name);
+            return emoji::subgroups()
+                .iter()
+                .find(|n| normalize_group(n) == wanted)
+                .map(|subgroup| Pattern::Subgroup(subgroup.to_string()))
+                .ok_or_else(|| {
+                    format!(
+                        "unknown emoji subgroup `{}` (expected a CLDR subgroup such as `face-smiling` or `country-flag`)",
+                        name
+                    )
+                });
+        }
+
+        if s.starts_with("U+") || s.starts_with("u+") {
+            let (start, end) = s.split_once("..").unwrap_or((s, s));
+            return match (parse_codepoint(start), parse_codepoint(end)) {
+                (Some(start), Some(end)) if start <= end => Ok(Pattern::Codepoints(start..=end)),
+                _ => Err(format!("invalid codepoint range `{}`", s)),
+            };
+        }
+
+        if s.is_empty() {
+            return Err("empty emoji pattern".to_string());
+        }
+        Ok(Pattern::Sequence(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for Pattern {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Sequence(s) => f.write_str(s),
+            Pattern::Codepoints(range) if range.start() == range.end() => {
+                write!(f, "U+{:04X}", *range.start() as u32)
+            }
+            Pattern::Codepoints(range) => {
+                write!(f, "U+{:04X}..U+{:04X}", *range.start() as u32, *range.end() as u32)
+            }
+            Pattern::Group(group) => write!(f, "group:{}", group_name(*group)),
+            Pattern::Subgroup(subgroup) => write!(f, "subgroup:{}", subgroup),
+        }
+    }
+}
+
+/// Whether `sequence` should be stripped.
+///
+/// The most specific matching pattern decides, with deny winning ties. A
+/// sequence matched by neither list is stripped unless a deny list is given,
+/// in which case only denied sequences are.
+pub(crate) fn is_stripped(sequence: &str, allow: &[Pattern], deny: &[Pattern]) -> bool {
+    let best = |patterns: &[Pattern]| {
+        patterns
+            .iter()
+            .filter(|p| p.matches(sequence))
+            .map(Pattern::specificity)
+            .max()
+    };
+
+    match (best(allow), best(deny)) {
+        (None, None) => deny.is_empty(),
+        (Some(_), None) => false,
+        (None, Some(_)) => true,
+        (Some(allowed), Some(denied)) => denied >= allowed,
+    }
+}
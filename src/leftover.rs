@@ -0,0 +1,190 @@
+//! Markdown elements that stripping leaves empty, and what to do about them.
+
+use std::{fmt, ops::Range, str::FromStr};
+
+use serde::Deserialize;
+
+/// A Markdown element that can be left with no content, such as `## 🎉`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Structure {
+    Heading,
+    /// A link whose text was only emojis: `[🔗](url)`.
+    Link,
+    ListItem,
+    TableCell,
+}
+
+impl Structure {
+    pub const ALL: [Structure; 4] = [
+        Structure::Heading,
+        Structure::Link,
+        Structure::ListItem,
+        Structure::TableCell,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Structure::Heading => "heading",
+            Structure::Link => "link",
+            Structure::ListItem => "list-item",
+            Structure::TableCell => "table-cell",
+        }
+    }
+}
+
+impl fmt::Display for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Structure {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Structure::ALL
+            .into_iter()
+            .find(|structure| structure.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = Structure::ALL.iter().map(|s| s.name()).collect();
+                format!("unknown Markdown element `{}` (expected one of: {})", s, names.join(", "))
+            })
+    }
+}
+
+impl TryFrom<String> for Structure {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// What happens to an element that stripping would leave empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Policy {
+    /// Strip as usual and report the empty element.
+    #[default]
+    Warn,
+    /// Remove the whole element. Table cells can't be removed without
+    /// shifting the columns after them, so this is rejected for them.
+    Delete,
+    /// Put the emojis' names in their place instead, so the element keeps
+    /// some text.
+    Name,
+}
+
+impl Policy {
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::Warn => "warn",
+            Policy::Delete => "delete",
+            Policy::Name => "name",
+        }
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warn" => Ok(Policy::Warn),
+            "delete" => Ok(Policy::Delete),
+            "name" => Ok(Policy::Name),
+            _ => Err(format!("unknown policy `{}` (expected `warn`, `delete` or `name`)", s)),
+        }
+    }
+}
+
+/// The policy for each kind of element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Policies {
+    pub heading: Policy,
+    pub link: Policy,
+    pub list_item: Policy,
+    pub table_cell: Policy,
+}
+
+impl Policies {
+    pub fn get(&self, structure: Structure) -> Policy {
+        match structure {
+            Structure::Heading => self.heading,
+            Structure::Link => self.link,
+            Structure::ListItem => self.list_item,
+            Structure::TableCell => self.table_cell,
+        }
+    }
+
+    pub fn set(&mut self, structure: Structure, policy: Policy) -> Result<(), String> {
+        if structure == Structure::TableCell && policy == Policy::Delete {
+            return Err("table cells can't be deleted; use `warn` or `name`".to_string());
+        }
+        match structure {
+            Structure::Heading => self.heading = policy,
+            Structure::Link => self.link = policy,
+            Structure::ListItem => self.list_item = policy,
+            Structure::TableCell => self.table_cell = policy,
+        }
+        Ok(())
+    }
+}
+
+/// An `<element>=<policy>` setting such as `heading=delete`; `all` stands
+/// for every element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    pub structures: Vec<Structure>,
+    pub policy: Policy,
+}
+
+impl FromStr for Setting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (structure, policy) = s
+            .split_once('=')
+            .ok_or_else(|| format!("invalid setting `{}` (expected `<element>=<policy>`)", s))?;
+        let policy: Policy = policy.parse()?;
+        let structures = match structure {
+            // Table cells have no `delete`; `all=delete` leaves them warning.
+            "all" if policy == Policy::Delete => Structure::ALL[..3].to_vec(),
+            "all" => Structure::ALL.to_vec(),
+            _ => vec![structure.parse()?],
+        };
+        let mut check = Policies::default();
+        for structure in &structures {
+            check.set(*structure, policy)?;
+        }
+        Ok(Setting { structures, policy })
+    }
+}
+
+/// An element that stripping left with no content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leftover {
+    pub structure: Structure,
+    /// Byte range of the whole element in the input text.
+    pub range: Range<usize>,
+    /// How it was dealt with.
+    pub policy: Policy,
+}
+
+impl fmt::Display for Leftover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.structure.name().replace('-', " ");
+        match self.policy {
+            Policy::Warn => write!(f, "stripping leaves an empty {}", name),
+            Policy::Delete => write!(f, "removed {} left empty by stripping", name),
+            Policy::Name => write!(f, "kept emoji names in {} that stripping would leave empty", name),
+        }
+    }
+}
@@ -0,0 +1,568 @@
+//! Strip emojis from Markdown and plain text.
+//!
+//! ```
+//! use remoji::{Options, Stripper};
+//!
+//! let stripper = Stripper::new(Options::default());
+//! assert_eq!(stripper.strip("Launch 🚀 now, `not 🚀 here`"), "Launch now, `not 🚀 here`");
+//! ```
+
+use std::{borrow::Cow, ops::Range};
+
+pub mod atomic;
+pub mod backup;
+mod cleanup;
+pub mod config;
+pub mod diff;
+mod emoji;
+pub mod filetype;
+pub mod filter;
+pub mod leftover;
+mod markdown;
+mod replace;
+pub mod report;
+pub mod suppress;
+pub mod walk;
+
+pub use filter::Pattern;
+pub use leftover::{Leftover, Policies, Policy, Structure};
+pub use markdown::Context;
+pub use replace::Replacement;
+pub use suppress::{Directive, Suppression};
+
+/// How the input text is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Syntax {
+    /// Only prose text nodes are touched; code, URLs and raw HTML are kept.
+    #[default]
+    Markdown,
+    /// Every emoji in the text is touched.
+    Plain,
+}
+
+/// Settings for a [`Stripper`].
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub syntax: Syntax,
+    /// Sequences that are kept. Variation selectors are ignored when
+    /// comparing, so `❤` also allows `❤️`.
+    pub allow: Vec<Pattern>,
+    /// Sequences that are stripped even if a less specific `allow` pattern
+    /// matches. When non-empty, only these are stripped.
+    pub deny: Vec<Pattern>,
+    pub replace: Replacement,
+    /// Markdown contexts whose prose is left alone.
+    pub skip: Vec<Context>,
+    /// What to do with Markdown elements that deleting emojis leaves empty.
+    pub leftovers: Policies,
+}
+
+/// One emoji sequence found in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Byte range of the sequence in the input text.
+    pub range: Range<usize>,
+    /// The removed sequence itself.
+    pub sequence: String,
+}
+
+impl Match {
+    /// The sequence's codepoints as `U+XXXX`, separated by spaces.
+    pub fn codepoints(&self) -> String {
+        emoji::codepoints(&self.sequence)
+    }
+}
+
+/// One change [`Stripper::process`] makes to the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    /// Byte range of the input that is replaced.
+    pub range: Range<usize>,
+    /// What goes in its place; empty for a deletion.
+    pub text: String,
+}
+
+/// Everything [`Stripper::process`] found and did.
+#[derive(Debug, Clone)]
+pub struct Stripped<'a> {
+    /// The cleaned text; borrowed when nothing changed.
+    pub text: Cow<'a, str>,
+    pub matches: Vec<Match>,
+    /// The changes that turn the input into `text`, in order. One edit can
+    /// remove several matches, such as a run of emojis or a whole heading.
+    pub edits: Vec<Edit>,
+    /// Markdown elements left empty by deleting emojis, outermost only.
+    pub leftovers: Vec<Leftover>,
+    /// Suppression comments that kept no emoji.
+    pub unused: Vec<Suppression>,
+}
+
+/// Everything [`Stripper::check`] found.
+#[derive(Debug, Clone)]
+pub struct Checked {
+    pub matches: Vec<Match>,
+    /// Suppression comments that kept no emoji.
+    pub unused: Vec<Suppression>,
+}
+
+/// The prose of a text and the emojis in it that are stripped.
+struct Scan {
+    regions: Vec<Range<usize>>,
+    matches: Vec<Match>,
+    unused: Vec<Suppression>,
+}
+
+/// One change to the input, turned into an [`Edit`] by [`Stripper::edits`].
+#[derive(Debug)]
+enum Cut {
+    /// Deleted emoji sequences, with the whitespace around them repaired.
+    Sequence { range: Range<usize>, region: Range<usize> },
+    /// Text put in place of an emoji sequence.
+    Replace { range: Range<usize>, with: String },
+    /// A whole link removed, with the whitespace around it repaired.
+    Inline { range: Range<usize>, region: Range<usize> },
+    /// Whole lines removed, for headings and list items.
+    Lines(Range<usize>),
+}
+
+impl Cut {
+    fn range(&self) -> &Range<usize> {
+        match self {
+            Cut::Sequence { range, .. } | Cut::Replace { range, .. } | Cut::Inline { range, .. } => range,
+            Cut::Lines(range) => range,
+        }
+    }
+}
+
+/// Finds and removes emojis according to its [`Options`].
+#[derive(Debug, Clone, Default)]
+pub struct Stripper {
+    options: Options,
+}
+
+impl Stripper {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Every emoji sequence in `text`, in order.
+    pub fn find(&self, text: &str) -> Vec<Match> {
+        self.scan(text).matches
+    }
+
+    /// Find the emojis in `text`, also reporting unused suppression comments.
+    pub fn check(&self, text: &str) -> Checked {
+        let scan = self.scan(text);
+        Checked {
+            matches: scan.matches,
+            unused: scan.unused,
+        }
+    }
+
+    /// `text` with its emojis removed or replaced; borrowed when nothing matched.
+    pub fn strip<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        self.strip_with_matches(text).0
+    }
+
+    /// The cleaned text together with the matches that were removed from it.
+    pub fn strip_with_matches<'a>(&self, text: &'a str) -> (Cow<'a, str>, Vec<Match>) {
+        let stripped = self.process(text);
+        (stripped.text, stripped.matches)
+    }
+
+    /// Strip `text`, also reporting the Markdown elements left empty and
+    /// unused suppression comments.
+    pub fn process<'a>(&self, text: &'a str) -> Stripped<'a> {
+        let Scan { regions, matches, unused } = self.scan(text);
+        let leftovers = self.leftovers(text, &matches);
+        let cuts = self.cuts(text, &regions, &matches, &leftovers);
+        let edits = self.edits(text, &cuts);
+        Stripped {
+            text: apply(text, &edits),
+            matches,
+            edits,
+            leftovers,
+            unused,
+        }
+    }
+
+    fn regions(&self, text: &str) -> Vec<Range<usize>> {
+        match self.options.syntax {
+            Syntax::Markdown => markdown::prose_ranges(text, &self.options.skip),
+            Syntax::Plain => std::iter::once(0..text.len()).collect(),
+        }
+    }
+
+    fn scan(&self, text: &str) -> Scan {
+        let regions = self.regions(text);
+        let mut matches = regions
+            .iter()
+            .flat_map(|region| {
+                emoji::sequences(&text[region.clone()])
+                    .map(move |m| region.start + m.start..region.start + m.end)
+            })
+            .filter(|range| {
+                filter::is_stripped(&text[range.clone()], &self.options.allow, &self.options.deny)
+            })
+            .map(|range| Match {
+                sequence: text[range.clone()].to_string(),
+                range,
+            })
+            .collect();
+
+        // Suppression comments are HTML, so only Markdown has them.
+        let unused = match self.options.syntax {
+            Syntax::Markdown if text.contains("remoji-") => {
+                suppress::Suppressions::parse(text, &markdown::html_ranges(text)).apply(&mut matches)
+            }
+            _ => Vec::new(),
+        };
+        Scan { regions, matches, unused }
+    }
+
+    /// Elements whose text would be nothing but deleted sequences and blanks.
+    fn leftovers(&self, text: &str, matches: &[Match]) -> Vec<Leftover> {
+        if matches.is_empty() || self.options.syntax != Syntax::Markdown || self.options.replace != Replacement::Delete {
+            return Vec::new();
+        }
+
+        let mut leftovers: Vec<Leftover> = Vec::new();
+        for element in markdown::elements(text) {
+            let first = matches.partition_point(|m| m.range.start < element.range.start);
+            let touched = matches.get(first).is_some_and(|m| m.range.end <= element.range.end);
+            if !touched
+                || element.other
+                || element.texts.is_empty()
+                || !element.texts.iter().all(|t| only_matches(text, t, matches))
+                || leftovers.last().is_some_and(|outer| outer.range.end >= element.range.end)
+            {
+                continue;
+            }
+            leftovers.push(Leftover {
+                structure: element.structure,
+                policy: self.options.leftovers.get(element.structure),
+                range: element.range,
+            });
+        }
+        leftovers
+    }
+
+    fn cuts(&self, text: &str, regions: &[Range<usize>], matches: &[Match], leftovers: &[Leftover]) -> Vec<Cut> {
+        let mut cuts = Vec::new();
+        for leftover in leftovers.iter().filter(|l| l.policy == Policy::Delete) {
+            let range = leftover.range.clone();
+            cuts.push(match leftover.structure {
+                Structure::Link => Cut::Inline {
+                    region: lines_of(text, &range),
+                    range,
+                },
+                _ => Cut::Lines(whole_lines(text, &range)),
+            });
+        }
+
+        // Matches, regions and leftovers are all in document order (and
+        // leftovers don't nest), so one cursor each finds what holds a match.
+        let named: Vec<&Leftover> = leftovers.iter().filter(|l| l.policy == Policy::Name).collect();
+        let mut leftover = 0;
+        let mut region = 0;
+        for m in matches {
+            let range = m.range.clone();
+            while named.get(leftover).is_some_and(|l| l.range.end <= range.start) {
+                leftover += 1;
+            }
+            let is_named = named
+                .get(leftover)
+                .is_some_and(|l| l.range.start <= range.start && range.end <= l.range.end);
+            cuts.push(if is_named {
+                Cut::Replace {
+                    with: Replacement::Name.render(&m.sequence),
+                    range,
+                }
+            } else if self.options.replace != Replacement::Delete {
+                Cut::Replace {
+                    with: self.options.replace.render(&m.sequence),
+                    range,
+                }
+            } else {
+                while regions.get(region).is_some_and(|r| r.end <= range.start) {
+                    region += 1;
+                }
+                Cut::Sequence {
+                    region: regions
+                        .get(region)
+                        .filter(|r| r.contains(&range.start))
+                        .map_or(0..text.len(), Range::clone),
+                    range,
+                }
+            });
+        }
+
+        // Outer cuts first, then drop whatever they cover.
+        cuts.sort_by_key(|cut| (cut.range().start, std::cmp::Reverse(cut.range().end)));
+        let mut covered = 0;
+        cuts.retain(|cut| {
+            let keep = cut.range().start >= covered;
+            if keep {
+                covered = cut.range().end;
+            }
+            keep
+        });
+        cuts
+    }
+
+    fn edits(&self, text: &str, cuts: &[Cut]) -> Vec<Edit> {
+        let mut edits = Vec::with_capacity(cuts.len());
+        let mut last = 0;
+        let mut i = 0;
+
+        while i < cuts.len() {
+            match &cuts[i] {
+                Cut::Sequence { range, region } | Cut::Inline { range, region } => {
+                    // Sequences separated by nothing but blanks are one deletion.
+                    let rest = cuts[i + 1..].iter().map_while(|cut| match cut {
+                        Cut::Sequence { range, .. } => Some(range.clone()),
+                        _ => None,
+                    });
+                    let (end, absorbed) = cleanup::absorb(text, region, range.end, rest);
+                    let removal = cleanup::removal(text, region, range.start..end, last);
+                    last = removal.range.end;
+                    edits.push(Edit {
+                        range: removal.range,
+                        text: if removal.space { " ".to_string() } else { String::new() },
+                    });
+                    i += 1 + absorbed;
+                }
+                Cut::Replace { range, with } => {
+                    last = range.end;
+                    edits.push(Edit {
+                        range: range.clone(),
+                        text: with.clone(),
+                    });
+                    i += 1;
+                }
+                Cut::Lines(range) => {
+                    last = range.end;
+                    edits.push(Edit {
+                        range: range.clone(),
+                        text: String::new(),
+                    });
+                    i += 1;
+                }
+            }
+        }
+
+        edits
+    }
+}
+
+/// `text` with `edits` (in order, not overlapping) made.
+fn apply<'a>(text: &'a str, edits: &[Edit]) -> Cow<'a, str> {
+    if edits.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for edit in edits {
+        out.push_str(&text[last..edit.range.start]);
+        out.push_str(&edit.text);
+        last = edit.range.end;
+    }
+    out.push_str(&text[last..]);
+
+    Cow::Owned(out)
+}
+
+/// Whether `range` holds nothing but `matches` (sorted) and whitespace.
+fn only_matches(text: &str, range: &Range<usize>, matches: &[Match]) -> bool {
+    let mut pos = range.start;
+    let first = matches.partition_point(|m| m.range.end <= range.start);
+    for m in matches[first..].iter().take_while(|m| m.range.start < range.end) {
+        if !text[pos..m.range.start.max(pos)].trim().is_empty() {
+            return false;
+        }
+        pos = pos.max(m.range.end);
+    }
+    pos >= range.end || text[pos..range.end].trim().is_empty()
+}
+
+/// The lines `range` starts and ends on, without the last line break; a
+/// link's text can wrap onto several.
+fn lines_of(text: &str, range: &Range<usize>) -> Range<usize> {
+    let start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[range.end..].find('\n').map_or(text.len(), |i| range.end + i);
+    start..end
+}
+
+/// The full lines of the block at `range`, up to its last non-blank line.
+/// When the block sits between blank lines, one of them goes too so they
+/// don't pile up.
+fn whole_lines(text: &str, range: &Range<usize>) -> Range<usize> {
+    let content_end = range.start + text[range.clone()].trim_end().len();
+    let start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let mut end = text[content_end..].find('\n').map_or(text.len(), |i| content_end + i + 1);
+
+    let blank_before = start == 0 || text[..start - 1].rsplit('\n').next().is_some_and(|l| l.trim().is_empty());
+    if let Some(next) = text[end..].split_inclusive('\n').next() {
+        if blank_before && next.trim().is_empty() {
+            end += next.len();
+        }
+    }
+    start..end
+}
+
+/// Remove emojis from Markdown `text` with the default options.
+pub fn strip(text: &str) -> Cow<'_, str> {
+    Stripper::default().strip(text)
+}
+
+/// Find emojis in Markdown `text` with the default options.
+pub fn find(text: &str) -> Vec<Match> {
+    Stripper::default().find(text)
+}
+
+/// 1-based line and column (in characters) of a byte offset.
+///
+/// This scans `text` up to `offset`; use a [`Locator`] for many offsets.
+pub fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Lines and columns of many byte offsets in one text.
+///
+/// A lookup on the same line as the previous one only counts the characters
+/// between the two; any other finds its line by binary search and counts
+/// from the start of it. Offsets in document order thus take one pass.
+#[derive(Debug, Clone)]
+pub struct Locator<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+    /// The previous offset, with its line and column.
+    last: (usize, usize, usize),
+}
+
+impl<'a> Locator<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            text,
+            line_starts,
+            last: (0, 1, 1),
+        }
+    }
+
+    /// 1-based line and column (in characters) of `offset`, as [`line_column`].
+    pub fn locate(&mut self, offset: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let (last_offset, last_line, last_column) = self.last;
+        let column = if line != last_line {
+            self.text[self.line_starts[line - 1]..offset].chars().count() + 1
+        } else if offset >= last_offset {
+            last_column + self.text[last_offset..offset].chars().count()
+        } else {
+            last_column - self.text[offset..last_offset].chars().count()
+        };
+        self.last = (offset, line, column);
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stripper(leftovers: &str) -> Stripper {
+        let mut options = Options::default();
+        for setting in leftovers.split_whitespace() {
+            let setting: leftover::Setting = setting.parse().unwrap();
+            for structure in setting.structures {
+                options.leftovers.set(structure, setting.policy).unwrap();
+            }
+        }
+        Stripper::new(options)
+    }
+
+    /// `(leftover settings, input, output, structures reported)`.
+    fn check(cases: &[(&str, &str, &str, &[&str])]) {
+        for &(settings, input, expected, structures) in cases {
+            let stripped = stripper(settings).process(input);
+            assert_eq!(stripped.text, expected, "{} {:?}", settings, input);
+            let found: Vec<_> = stripped.leftovers.iter().map(|l| l.structure.name()).collect();
+            assert_eq!(found, structures, "{} {:?}", settings, input);
+        }
+    }
+
+    #[test]
+    fn leftovers_warn() {
+        check(&[
+            ("all=warn", "## 🎉\n\nText\n", "## \n\nText\n", &["heading"]),
+            ("all=warn", "🎉\n---\n\nText\n", "\n---\n\nText\n", &["heading"]),
+            ("all=warn", "Title 🎉\n=====\n", "Title\n=====\n", &[]),
+            ("all=warn", "See [🔗](https://x) now\n", "See [](https://x) now\n", &["link"]),
+            ("all=warn", "- one\n- ✨\n- three\n", "- one\n- \n- three\n", &["list-item"]),
+            ("all=warn", "| a | b |\n|---|---|\n| ✅ | x |\n", "| a | b |\n|---|---|\n|  | x |\n", &["table-cell"]),
+            ("all=warn", "## 🎉 `code`\n", "## `code`\n", &[]),
+            ("all=warn", "- 🚀 ![logo](x.png)\n", "- ![logo](x.png)\n", &[]),
+        ]);
+    }
+
+    #[test]
+    fn leftovers_delete() {
+        check(&[
+            ("all=delete", "# Intro\n\n## 🎉\n\nText\n", "# Intro\n\nText\n", &["heading"]),
+            ("all=delete", "🎉\n---\n\nText\n", "Text\n", &["heading"]),
+            ("all=delete", "See [🔗](https://x) now\n", "See now\n", &["link"]),
+            ("all=delete", "See [🔗 \n](https://x) now\n", "See now\n", &["link"]),
+            ("all=delete", "- one\n- ✨\n- three\n", "- one\n- three\n", &["list-item"]),
+            // Table cells can't be deleted, so `all` leaves them warning.
+            ("all=delete", "| a | b |\n|---|---|\n| ✅ | x |\n", "| a | b |\n|---|---|\n|  | x |\n", &["table-cell"]),
+            ("link=delete", "## 🎉 [🔗](https://x) ok\n", "## ok\n", &["link"]),
+        ]);
+    }
+
+    #[test]
+    fn leftovers_name() {
+        check(&[
+            ("all=name", "## 🎉\n\nText\n", "## party popper\n\nText\n", &["heading"]),
+            ("all=name", "🎉\n---\n", "party popper\n---\n", &["heading"]),
+            ("all=name", "See [🔗](https://x) now 🚀\n", "See [link](https://x) now\n", &["link"]),
+            ("all=name", "- ✨\n", "- sparkles\n", &["list-item"]),
+            ("all=name", "| ✅ | x |\n|---|---|\n", "| check mark button | x |\n|---|---|\n", &["table-cell"]),
+            ("heading=name", "## 🎉\n\nSee [🔗](u) 🚀\n", "## party popper\n\nSee [](u)\n", &["heading", "link"]),
+        ]);
+    }
+
+    #[test]
+    fn nested_items() {
+        check(&[
+            ("all=warn", "- one\n  - 🚀\n- three\n", "- one\n  - \n- three\n", &["list-item"]),
+            ("all=delete", "- one\n  - 🚀\n- three\n", "- one\n- three\n", &["list-item"]),
+            ("all=name", "- one\n  - 🚀\n- three\n", "- one\n  - rocket\n- three\n", &["list-item"]),
+            // An item whose sub-items are all empty is empty too.
+            ("all=delete", "- 🚀\n  - 🎉\n- three\n", "- three\n", &["list-item"]),
+            ("all=name", "- 🚀\n  - 🎉\n", "- rocket\n  - party popper\n", &["list-item"]),
+            ("all=delete", "- 🚀\n  - x\n", "- \n  - x\n", &[]),
+        ]);
+    }
+
+    #[test]
+    fn only_the_outermost_leftover_is_reported() {
+        check(&[
+            ("all=warn", "## [🔗](https://x)\n", "## [](https://x)\n", &["heading"]),
+            ("all=delete", "## [🔗](https://x)\n\nText\n", "Text\n", &["heading"]),
+            ("all=name", "## [🔗](https://x)\n", "## [link](https://x)\n", &["heading"]),
+            ("all=warn", "- [🚀](https://x) 🎉\n- two\n", "- [](https://x)\n- two\n", &["list-item"]),
+            ("all=delete", "- [🚀](https://x) 🎉\n- two\n", "- two\n", &["list-item"]),
+            ("all=warn", "| [🔗](https://x) | 🚀 |\n|---|---|\n", "| [](https://x) |  |\n|---|---|\n", &["table-cell", "table-cell"]),
+        ]);
+    }
+}
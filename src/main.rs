@@ -1,8 +1,29 @@
-use std::{fs, path::{Path, PathBuf}};
-use clap::Parser;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{self, Write as _},
+    fs,
+    io::{IsTerminal, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use clap::{Args, Parser, Subcommand};
+use filetime::FileTime;
 use anyhow::{Context, Result};
-use regex::Regex;
-use walkdir::WalkDir;
+use rayon::prelude::*;
+use remoji::{
+    atomic::{self, Transaction, WriteOptions},
+    backup::{self, Retention, Snapshot},
+    config::{Config, Loader},
+    diff,
+    filetype::Mapping,
+    leftover::{self, Leftover, Policy},
+    report::{self, ErrorRecord, FileRecord, Findings, Format, Report, Status},
+    walk::{self, Scan, WalkOptions},
+    Context as MarkdownContext, Locator, Pattern, Replacement, Stripper, Suppression,
+};
 
 #[derive(Parser)]
 #[command(
@@ -10,21 +31,37 @@ use walkdir::WalkDir;
     version,
     about = "Remove emojis from markdown files",
     long_about = "A CLI tool to strip emojis from markdown files. \
-                  Can process single files or recursively scan directories."
+                  Can process single files or recursively scan directories.",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true,
+    after_help = "Exit status: 0 on success, 1 when `check` finds emojis, 2 when any file or directory fails."
 )]
 struct Cli {
-    /// Path to a markdown file or directory containing .md files
-    #[arg(short, long, value_name = "PATH")]
-    path: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    input: InputArgs,
 
-    /// Recursively process all .md files in the directory (replaces files in-place)
+    /// Rewrite files in place; implied by directories, globs, several paths and --files-from
     #[arg(short, long)]
     recursive: bool,
 
-    /// Output file path (only works with single file mode, ignored with --recursive)
+    #[command(flatten)]
+    batch: BatchArgs,
+
+    /// Output file path (only works with a single input file, ignored when rewriting in place)
     #[arg(short, long, value_name = "FILE")]
     output: Option<PathBuf>,
 
+    /// Write cleaned copies into a mirrored tree under DIR instead of rewriting files in place
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["output", "transaction", "backup", "backup_dir"])]
+    out_dir: Option<PathBuf>,
+
+    /// With --out-dir, also copy the files that aren't processed, for a complete tree
+    #[arg(long, requires = "out_dir")]
+    copy_other: bool,
+
     /// Show detailed processing information
     #[arg(short, long)]
     verbose: bool,
@@ -33,42 +70,658 @@ struct Cli {
     #[arg(short = 'd', long)]
     dry_run: bool,
 
-    /// Create backup files (.bak) before modifying (only with --recursive)
+    /// Print a unified diff of the changes instead of writing them (implies --dry-run)
+    #[arg(long)]
+    diff: bool,
+
+    #[command(flatten)]
+    backup: BackupArgs,
+
+    /// Keep the modification time of rewritten files
+    #[arg(long)]
+    keep_mtime: bool,
+
+    /// Modify no file unless every file can be processed, and undo the
+    /// files already replaced if a later one can't be
+    #[arg(long)]
+    transaction: bool,
+
+    /// What to put in place of each emoji: delete, shortcode, name, text:<string> or template:<template>
+    #[arg(long, value_name = "MODE")]
+    replace: Option<Replacement>,
+
+    /// How to report results: human, json or jsonl
+    #[arg(long, value_name = "FORMAT", default_value_t, conflicts_with_all = ["output", "diff"])]
+    format: Format,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+
+    #[command(flatten)]
+    walk: WalkArgs,
+}
+
+/// The files, directories and glob patterns to process.
+#[derive(Args)]
+struct InputArgs {
+    /// Files, directories or glob patterns to process; `-` reads standard input
+    #[arg(value_name = "PATH", required_unless_present_any = ["path", "files_from"])]
+    paths: Vec<PathBuf>,
+
+    /// Same as a positional PATH (repeatable)
+    #[arg(short, long, value_name = "PATH")]
+    path: Vec<PathBuf>,
+
+    /// Also process the paths listed in FILE (`-` for standard input), separated by NUL bytes or newlines
+    #[arg(long, value_name = "FILE")]
+    files_from: Option<PathBuf>,
+
+    /// File name to assume for standard input, for config lookup and file type detection
+    #[arg(long, value_name = "PATH")]
+    stdin_filename: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn paths(&self) -> Vec<PathBuf> {
+        self.paths.iter().chain(&self.path).cloned().collect()
+    }
+
+    /// The single file or standard input named on its own, if that is all
+    /// the input there is.
+    fn single_file(&self) -> Option<PathBuf> {
+        match self.paths().as_slice() {
+            [path] if self.files_from.is_none() && !path.is_dir() && !walk::is_glob(path) => {
+                Some(path.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Every file to process, de-duplicated and sorted by path, along with
+    /// the entries that could not be scanned.
+    fn files(&self, walk: &WalkArgs, loader: &mut Loader) -> Result<Scan> {
+        let paths = self.paths();
+        let listed = match &self.files_from {
+            Some(list) => {
+                if is_stdin(list) && paths.iter().any(|p| is_stdin(p)) {
+                    anyhow::bail!("Standard input can't be both a path and the --files-from list");
+                }
+                read_file_list(list)?
+            }
+            None => Vec::new(),
+        };
+        let mut scan = walk::expand(&paths, &listed, &walk.options(), loader)?;
+        scan.files.sort();
+        Ok(scan)
+    }
+}
+
+/// Paths from a `--files-from` list, as written by `git ls-files -z`,
+/// `fd -0` or `find -print0`, or one per line when there is no NUL byte.
+fn read_file_list(list: &Path) -> Result<Vec<PathBuf>> {
+    let text = read_input(list)?;
+    let separator = if text.contains('\0') { '\0' } else { '\n' };
+    Ok(text
+        .split(separator)
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Options shared by every command that override `.remoji.toml`.
+#[derive(Args)]
+struct ConfigArgs {
+    /// Keep these emojis: a sequence, `U+XXXX[..U+YYYY]`, `group:<name>` or `subgroup:<name>` (repeatable)
+    #[arg(long, value_name = "PATTERN")]
+    allow: Vec<Pattern>,
+
+    /// Strip these emojis even if a broader --allow matches; when given, only these are stripped
+    #[arg(long, value_name = "PATTERN")]
+    deny: Vec<Pattern>,
+
+    /// Leave prose inside these Markdown contexts alone (e.g. heading,table)
+    #[arg(long, value_name = "CONTEXT", value_delimiter = ',')]
+    skip: Vec<MarkdownContext>,
+
+    /// Only process files matching this glob when scanning directories (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    include: Vec<String>,
+
+    /// Skip files matching this glob when scanning directories (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Map a file type to a processor, e.g. `*.rst=plain` or `LICENSE=ignore` (repeatable)
+    #[arg(long, value_name = "PATTERN=PROCESSOR")]
+    type_add: Vec<Mapping>,
+
+    /// What to do with a Markdown element that deleting emojis leaves empty,
+    /// e.g. `heading=delete` or `all=name` (repeatable; warn, delete or name)
+    #[arg(long, value_name = "ELEMENT=POLICY")]
+    leftover: Vec<leftover::Setting>,
+
+    /// Ignore .remoji.toml files
+    #[arg(long)]
+    no_config: bool,
+}
+
+/// Options controlling the copies kept of modified files.
+#[derive(Args)]
+struct BackupArgs {
+    /// Create backup files (.bak) before modifying (only when rewriting in place)
     #[arg(short, long)]
     backup: bool,
+
+    /// Back up modified files into a timestamped run under DIR instead of .bak files
+    #[arg(long, value_name = "DIR")]
+    backup_dir: Option<PathBuf>,
+
+    /// Keep only the newest N runs in --backup-dir
+    #[arg(long, value_name = "N", requires = "backup_dir", value_parser = clap::value_parser!(u64).range(1..))]
+    backup_keep: Option<u64>,
+
+    /// Remove runs older than this from --backup-dir, e.g. `30days` or `12h`
+    #[arg(long, value_name = "DURATION", requires = "backup_dir", value_parser = humantime::parse_duration)]
+    backup_max_age: Option<Duration>,
+}
+
+impl BackupArgs {
+    fn snapshot(&self) -> Result<Option<Snapshot>> {
+        self.backup_dir.as_deref().map(Snapshot::new).transpose()
+    }
+
+    fn retention(&self) -> Retention {
+        Retention {
+            keep: self.backup_keep.map(|n| n as usize),
+            max_age: self.backup_max_age,
+        }
+    }
+}
+
+/// Options controlling how a batch of files is worked through.
+#[derive(Args)]
+struct BatchArgs {
+    /// Number of files to process in parallel (defaults to the number of CPUs)
+    #[arg(short, long, value_name = "N")]
+    jobs: Option<usize>,
+
+    /// Stop at the first file that fails instead of carrying on with the rest
+    #[arg(long)]
+    fail_fast: bool,
+}
+
+impl BatchArgs {
+    /// Run `work` on every item in parallel, returning the results in the
+    /// order of `items`. With `--fail-fast`, items not yet started when one
+    /// fails are skipped and come back as `None`.
+    fn run<I, T, F>(&self, items: &[I], work: F) -> Result<Vec<Finished<T>>>
+    where
+        I: Sync,
+        T: Send,
+        F: Fn(&I, &mut Output) -> Result<T> + Sync,
+    {
+        let failed = AtomicBool::new(false);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.jobs.unwrap_or(0))
+            .build()
+            .context("Could not start worker threads")?;
+        Ok(pool.install(|| {
+            items
+                .par_iter()
+                .map(|item| {
+                    if self.fail_fast && failed.load(Ordering::Relaxed) {
+                        return None;
+                    }
+                    let mut out = Output::default();
+                    let result = work(item, &mut out);
+                    if result.is_err() {
+                        failed.store(true, Ordering::Relaxed);
+                    }
+                    Some((out, result))
+                })
+                .collect()
+        }))
+    }
+}
+
+/// What one item printed, and how it ended; `None` if it never ran.
+type Finished<T> = Option<(Output, Result<T>)>;
+
+/// Text a worker would have printed, held back so it comes out in order.
+#[derive(Default)]
+struct Output {
+    stdout: String,
+    stderr: String,
+    /// What was found, for the machine-readable formats.
+    findings: Findings,
+}
+
+impl Output {
+    fn print(&self) {
+        print!("{}", self.stdout);
+        eprint!("{}", self.stderr);
+    }
+}
+
+/// Paths that could not be processed, reported together at the end of a run.
+#[derive(Default)]
+struct Failures(Vec<(PathBuf, String)>);
+
+impl Failures {
+    fn push(&mut self, path: &Path, cause: impl fmt::Display) {
+        self.0.push((path.to_path_buf(), cause.to_string()));
+    }
+
+    fn extend_walk(&mut self, errors: &[walk::WalkError]) {
+        for error in errors {
+            self.push(&error.path, error.cause());
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Print every failure to stderr.
+    fn report(&self) {
+        if self.0.is_empty() {
+            return;
+        }
+        eprintln!("\n{} failed:", plural(self.0.len(), "path", "paths"));
+        for (path, cause) in &self.0 {
+            eprintln!("  {}: {}", path.display(), cause);
+        }
+    }
+}
+
+fn plural(n: usize, one: &str, many: &str) -> String {
+    format!("{} {}", n, if n == 1 { one } else { many })
+}
+
+/// Options controlling which files a directory scan visits.
+#[derive(Args)]
+struct WalkArgs {
+    /// Also scan hidden files and directories
+    #[arg(long)]
+    hidden: bool,
+
+    /// Don't respect .gitignore, .ignore and .remojiignore files
+    #[arg(long)]
+    no_ignore: bool,
+
+    /// Follow symbolic links
+    #[arg(short = 'L', long)]
+    follow: bool,
+
+    /// Descend at most this many directories below the path
+    #[arg(long, value_name = "NUM")]
+    max_depth: Option<usize>,
+
+    /// Print the files that would be processed, then exit
+    #[arg(long)]
+    list_files: bool,
+}
+
+impl WalkArgs {
+    fn options(&self) -> WalkOptions {
+        WalkOptions {
+            hidden: self.hidden,
+            follow_links: self.follow,
+            max_depth: self.max_depth,
+            no_ignore: self.no_ignore,
+        }
+    }
+}
+
+impl ConfigArgs {
+    fn loader(&self, mut overrides: Config) -> Loader {
+        if !self.allow.is_empty() {
+            overrides.allow = Some(self.allow.clone());
+        }
+        if !self.deny.is_empty() {
+            overrides.deny = Some(self.deny.clone());
+        }
+        if !self.include.is_empty() {
+            overrides.include = Some(self.include.clone());
+        }
+        if !self.exclude.is_empty() {
+            overrides.exclude = Some(self.exclude.clone());
+        }
+        if !self.type_add.is_empty() {
+            let mappings = self.type_add.iter().map(|m| (m.pattern.clone(), m.processor));
+            overrides.file_types = Some(mappings.collect());
+        }
+        if !self.skip.is_empty() {
+            overrides.skip = Some(self.skip.clone());
+        }
+        if !self.leftover.is_empty() {
+            let settings = self
+                .leftover
+                .iter()
+                .flat_map(|s| s.structures.iter().map(|structure| (*structure, s.policy)));
+            overrides.leftovers = Some(settings.collect());
+        }
+        Loader::new(overrides, !self.no_config)
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Report emojis without modifying files; exits with 1 if any are found
+    Check(Box<CheckArgs>),
+    /// Put back files saved by a run with --backup-dir
+    Restore(RestoreArgs),
+}
+
+#[derive(Args)]
+struct RestoreArgs {
+    /// Only restore these files, or the files under these directories
+    #[arg(value_name = "PATH")]
+    paths: Vec<PathBuf>,
+
+    /// The directory given to --backup-dir
+    #[arg(long, value_name = "DIR")]
+    backup_dir: PathBuf,
+
+    /// Run to restore from (defaults to the latest; see --list)
+    #[arg(long, value_name = "ID")]
+    run: Option<String>,
+
+    /// List the runs in the backup directory, then exit
+    #[arg(long)]
+    list: bool,
+
+    /// Show what would be restored without writing anything
+    #[arg(short = 'd', long)]
+    dry_run: bool,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    batch: BatchArgs,
+
+    /// How to report results: human, json, jsonl or sarif
+    #[arg(long, value_name = "FORMAT", default_value_t)]
+    format: Format,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+
+    #[command(flatten)]
+    walk: WalkArgs,
 }
 
-fn main() -> Result<()> {
+/// Exit status when `check` finds emojis.
+const EXIT_FOUND: u8 = 1;
+/// Exit status for runtime errors, including any file or directory that
+/// failed in a batch; matches clap's status for usage errors.
+const EXIT_ERROR: u8 = 2;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn run() -> Result<ExitCode> {
     let args = Cli::parse();
 
-    if args.recursive {
-        if !args.path.is_dir() {
-            return Err(anyhow::anyhow!("Path must be a directory when using --recursive"));
+    match &args.command {
+        Some(Command::Check(check)) => return run_check(check),
+        Some(Command::Restore(restore)) => return run_restore(restore),
+        None => {}
+    }
+    if args.format == Format::Sarif {
+        anyhow::bail!("--format sarif is only available for `remoji check`");
+    }
+
+    let mut loader = args.config.loader(Config {
+        replace: args.replace.clone(),
+        ..Config::default()
+    });
+
+    if args.walk.list_files {
+        return list_files(&args.input, &args.walk, &mut loader);
+    }
+
+    let single_file = args
+        .input
+        .single_file()
+        .filter(|_| !args.recursive && args.out_dir.is_none());
+    if let Some(path) = single_file.as_deref() {
+        if args.format != Format::Human {
+            anyhow::bail!(
+                "--format {} reports on files rewritten in place; add --recursive to rewrite `{}`, or use `remoji check`",
+                args.format,
+                path.display()
+            );
         }
-        process_directory(&args)?;
+        let name = input_name(path, args.input.stdin_filename.as_deref());
+        let settings = loader.settings_for(name)?;
+        // Editors pipe every buffer through formatters; files the config
+        // doesn't select come back unchanged rather than as an error.
+        if is_stdin(path) && args.input.stdin_filename.is_some() && !settings.selects(name) {
+            process_file(path, name, &args, None)?;
+        } else {
+            process_file(path, name, &args, Some(&Stripper::new(settings.options_for(name))))?;
+        }
+        Ok(ExitCode::SUCCESS)
     } else {
-        process_file(&args)?;
+        let scan = args.input.files(&args.walk, &mut loader)?;
+        process_files(&scan, &args, &mut loader)
     }
+}
 
-    Ok(())
+/// `-` as a path stands for standard input.
+fn is_stdin(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
+/// The name `path` is reported under and configured by.
+fn input_name<'a>(path: &'a Path, stdin_filename: Option<&'a Path>) -> &'a Path {
+    match stdin_filename {
+        Some(name) if is_stdin(path) => name,
+        _ => path,
+    }
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if is_stdin(path) {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context("Could not read standard input")?;
+        Ok(content)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Could not read file `{}`", path.display()))
+    }
 }
 
-fn remove_emojis(content: &str) -> String {
-    let re_with_spaces = Regex::new(r" \p{Emoji}+ ").unwrap();
-    let temp = re_with_spaces.replace_all(content, " ");
+fn list_files(input: &InputArgs, walk: &WalkArgs, loader: &mut Loader) -> Result<ExitCode> {
+    let scan = input.files(walk, loader)?;
+    for file in &scan.files {
+        println!("{}", file.display());
+    }
 
-    let re_emoji = Regex::new(r"\p{Emoji}+").unwrap();
-    re_emoji.replace_all(&temp, "").to_string()
+    let mut failures = Failures::default();
+    failures.extend_walk(&scan.errors);
+    failures.report();
+    Ok(exit_code(&failures, ExitCode::SUCCESS))
 }
 
-fn process_file(args: &Cli) -> Result<()> {
-    let content = fs::read_to_string(&args.path)
-        .with_context(|| format!("Could not read file `{}`", args.path.display()))?;
+/// Print `report` to stdout with every failure, unless the format is human.
+fn emit(format: Format, mut report: Report, failures: &Failures) -> Result<()> {
+    if format == Format::Human {
+        return Ok(());
+    }
+    report.errors = failures.0.iter().map(|(path, cause)| ErrorRecord::new(path, cause)).collect();
+    report
+        .write(format, std::io::stdout().lock())
+        .context("Could not write the report")
+}
 
-    let cleaned_content = remove_emojis(&content);
+/// `otherwise` unless something failed.
+fn exit_code(failures: &Failures, otherwise: ExitCode) -> ExitCode {
+    if failures.is_empty() {
+        otherwise
+    } else {
+        ExitCode::from(EXIT_ERROR)
+    }
+}
+
+fn check_file(file_path: &Path, name: &Path, stripper: &Stripper, format: Format, out: &mut Output) -> Result<usize> {
+    let content = read_input(file_path)?;
+
+    if format != Format::Human {
+        // The edits stripping would make become the suggested fixes.
+        let stripped = stripper.process(&content);
+        out.findings = Findings::new(&content, &stripped.matches, &stripped.edits, &[], &stripped.unused);
+        return Ok(stripped.matches.len());
+    }
+    let checked = stripper.check(&content);
+    let mut locator = Locator::new(&content);
+    for m in &checked.matches {
+        let (line, column) = locator.locate(m.range.start);
+        let _ = writeln!(out.stdout, "{}:{}:{}: {} {}", name.display(), line, column, m.codepoints(), m.sequence);
+    }
+    write_warnings(name, &content, &[], &checked.unused, &mut out.stderr);
+
+    Ok(checked.matches.len())
+}
+
+/// Append a warning, in document order, for each element stripping left
+/// empty under the `warn` policy and each unused suppression comment.
+fn write_warnings(name: &Path, text: &str, leftovers: &[Leftover], unused: &[Suppression], err: &mut String) {
+    let mut warnings: Vec<(usize, String)> = leftovers
+        .iter()
+        .filter(|l| l.policy == Policy::Warn)
+        .map(|l| (l.range.start, l.to_string()))
+        .chain(unused.iter().map(|s| (s.range.start, s.to_string())))
+        .collect();
+    warnings.sort_by_key(|(offset, _)| *offset);
+    let mut locator = Locator::new(text);
+    for (offset, message) in warnings {
+        let (line, column) = locator.locate(offset);
+        let _ = writeln!(err, "{}:{}:{}: warning: {}", name.display(), line, column, message);
+    }
+}
+
+fn run_check(args: &CheckArgs) -> Result<ExitCode> {
+    let mut loader = args.config.loader(Config::default());
+    if args.walk.list_files {
+        return list_files(&args.input, &args.walk, &mut loader);
+    }
+
+    let scan = args.input.files(&args.walk, &mut loader)?;
+    let mut failures = Failures::default();
+    let mut report = Report::new(report::Command::Check);
+    failures.extend_walk(&scan.errors);
+    if args.batch.fail_fast && !failures.is_empty() {
+        failures.report();
+        emit(args.format, report, &failures)?;
+        return Ok(ExitCode::from(EXIT_ERROR));
+    }
+
+    let mut work = Vec::new();
+    for file in scan.files {
+        let name = input_name(&file, args.input.stdin_filename.as_deref()).to_path_buf();
+        let stripper = Stripper::new(loader.settings_for(&name)?.options_for(&name));
+        work.push((file, name, stripper));
+    }
+
+    // Results are gathered in path order and printed once every worker is done.
+    let results = args
+        .batch
+        .run(&work, |(file, name, stripper), out| check_file(file, name, stripper, args.format, out))?;
+
+    let mut found = 0;
+    let mut files = 0;
+    let mut not_checked = 0;
+    for ((_, name, _), result) in work.iter().zip(results) {
+        let Some((out, result)) = result else {
+            not_checked += 1;
+            report.files.push(FileRecord::new(name, Status::NotProcessed));
+            continue;
+        };
+        if args.format == Format::Human {
+            out.print();
+        }
+        match result {
+            Ok(0) => report.files.push(FileRecord::new(name, Status::Clean).with_findings(out.findings)),
+            Ok(n) => {
+                found += n;
+                files += 1;
+                report.files.push(FileRecord::new(name, Status::Found).with_findings(out.findings));
+            }
+            Err(e) => {
+                let message = format!("{:#}", e);
+                report.files.push(FileRecord::new(name, Status::Failed).with_message(&message));
+                failures.push(name, message);
+            }
+        }
+    }
+
+    if found > 0 && args.format == Format::Human {
+        println!("\nFound {} in {}", plural(found, "emoji", "emojis"), plural(files, "file", "files"));
+    }
+    if not_checked > 0 {
+        eprintln!("\nStopped at the first error; {} not checked", plural(not_checked, "file was", "files were"));
+    }
+    failures.report();
+    emit(args.format, report, &failures)?;
+    let otherwise = if found > 0 {
+        ExitCode::from(EXIT_FOUND)
+    } else {
+        ExitCode::SUCCESS
+    };
+    Ok(exit_code(&failures, otherwise))
+}
+
+fn render_diff(path: &Path, original: &str, cleaned: &str) -> String {
+    let diff = diff::unified(path, original, cleaned);
+    if std::io::stdout().is_terminal() {
+        diff::colorize(&diff)
+    } else {
+        diff
+    }
+}
+
+/// Strip `path` (or standard input) to stdout or `--output`. Without a
+/// stripper the content is passed through unchanged.
+fn process_file(path: &Path, name: &Path, args: &Cli, stripper: Option<&Stripper>) -> Result<()> {
+    let content = read_input(path)?;
+
+    let cleaned_content = match stripper {
+        Some(stripper) => {
+            let stripped = stripper.process(&content);
+            let mut warnings = String::new();
+            write_warnings(name, &content, &stripped.leftovers, &stripped.unused, &mut warnings);
+            eprint!("{}", warnings);
+            stripped.text
+        }
+        None => Cow::Borrowed(content.as_str()),
+    };
+
+    if args.diff {
+        print!("{}", render_diff(name, &content, &cleaned_content));
+        return Ok(());
+    }
 
     if args.dry_run {
-        println!("[DRY RUN] Would process: {}", args.path.display());
+        println!("[DRY RUN] Would process: {}", name.display());
         if args.verbose {
             println!("Original length: {} bytes", content.len());
             println!("Cleaned length: {} bytes", cleaned_content.len());
@@ -77,11 +730,11 @@ fn process_file(args: &Cli) -> Result<()> {
     }
 
     match &args.output {
-        Some(path) => {
-            fs::write(path, cleaned_content.as_bytes())
-                .with_context(|| format!("Could not write to file `{}`", path.display()))?;
+        Some(output) => {
+            fs::write(output, cleaned_content.as_bytes())
+                .with_context(|| format!("Could not write to file `{}`", output.display()))?;
             if args.verbose {
-                println!("Successfully stripped emojis and saved to {}", path.display());
+                println!("Successfully stripped emojis and saved to {}", output.display());
             }
         }
         None => {
@@ -92,63 +745,509 @@ fn process_file(args: &Cli) -> Result<()> {
     Ok(())
 }
 
-fn process_file_in_place(file_path: &Path, args: &Cli) -> Result<()> {
-    let content = fs::read_to_string(file_path)
-        .with_context(|| format!("Could not read file `{}`", file_path.display()))?;
+/// Where `--out-dir` puts the copy of each file.
+struct Mirror {
+    out_dir: PathBuf,
+    /// The directory arguments; files found in one are placed relative to it.
+    roots: Vec<Root>,
+    cwd: PathBuf,
+}
+
+/// A directory argument and where its files go in the mirror: the same
+/// place relative to `--out-dir` as the directory has relative to the
+/// common ancestor of all directory arguments. With one directory, that is
+/// `--out-dir` itself.
+struct Root {
+    path: PathBuf,
+    place: PathBuf,
+}
+
+impl Mirror {
+    fn new(out_dir: &Path, input: &InputArgs) -> Result<Self> {
+        let paths: Vec<PathBuf> = input.paths().into_iter().filter(|p| p.is_dir()).collect();
+        let canonical = paths
+            .iter()
+            .map(|p| fs::canonicalize(p).with_context(|| format!("Could not resolve `{}`", p.display())))
+            .collect::<Result<Vec<_>>>()?;
+        let ancestor = common_ancestor(&canonical).unwrap_or_default();
+        let roots = paths
+            .into_iter()
+            .zip(&canonical)
+            .map(|(path, canonical)| Root {
+                place: canonical.strip_prefix(&ancestor).unwrap_or(canonical).to_path_buf(),
+                path,
+            })
+            .collect();
+        Ok(Self {
+            out_dir: std::path::absolute(out_dir)
+                .with_context(|| format!("Could not resolve `{}`", out_dir.display()))?,
+            roots,
+            cwd: std::env::current_dir().context("Could not read the current directory")?,
+        })
+    }
 
-    let cleaned_content = remove_emojis(&content);
+    /// `file`'s place in the mirror: its path relative to the directory
+    /// argument it was found in, or else relative to the current directory.
+    fn target(&self, file: &Path) -> Result<PathBuf> {
+        let found = self
+            .roots
+            .iter()
+            .find_map(|root| Some((root, file.strip_prefix(&root.path).ok()?)));
+        if let Some((root, relative)) = found {
+            return Ok(self.out_dir.join(&root.place).join(relative));
+        }
+        let absolute = std::path::absolute(file)?;
+        match absolute.strip_prefix(&self.cwd) {
+            Ok(relative) => Ok(self.out_dir.join(relative)),
+            Err(_) => anyhow::bail!("`{}` is outside the current directory and has no place in --out-dir", file.display()),
+        }
+    }
+
+    /// Write `contents` as the copy of `file`, with `file`'s permissions.
+    fn write(&self, file: &Path, contents: &[u8], keep_mtime: bool) -> Result<PathBuf> {
+        let target = self.target(file)?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("Could not create `{}`", parent.display()))?;
+        }
+        let metadata = fs::metadata(file).with_context(|| format!("Could not read metadata of `{}`", file.display()))?;
+        fs::write(&target, contents)
+            .and_then(|()| fs::set_permissions(&target, metadata.permissions()))
+            .with_context(|| format!("Could not write to file `{}`", target.display()))?;
+        if keep_mtime {
+            filetime::set_file_mtime(&target, FileTime::from_last_modification_time(&metadata))
+                .with_context(|| format!("Could not set the modification time of `{}`", target.display()))?;
+        }
+        Ok(target)
+    }
+
+    /// Fail if two of `files` would be written to the same place.
+    fn check_targets<'a>(&self, files: impl IntoIterator<Item = &'a Path>) -> Result<()> {
+        let mut targets: HashMap<PathBuf, &Path> = HashMap::new();
+        for file in files {
+            // Files without a place fail on their own when written.
+            let Ok(target) = self.target(file) else { continue };
+            if let Some(other) = targets.insert(target.clone(), file) {
+                anyhow::bail!(
+                    "`{}` and `{}` would both be written to `{}`",
+                    other.display(),
+                    file.display(),
+                    target.display()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy `file` into the mirror unchanged.
+    fn copy(&self, file: &Path, keep_mtime: bool) -> Result<PathBuf> {
+        let contents = fs::read(file).with_context(|| format!("Could not read file `{}`", file.display()))?;
+        self.write(file, &contents, keep_mtime)
+    }
+}
+
+/// What happened to one file when rewriting in place.
+enum Outcome {
+    Changed,
+    /// Nothing to strip; the file was neither written nor backed up.
+    Unchanged,
+    /// Not processed at all, for the given reason.
+    Skipped(&'static str),
+    /// Ready to be put in place once every file of the transaction is.
+    Staged(Box<Staged>),
+}
+
+/// A file prepared by a `--transaction` run, and what to roll it back to.
+struct Staged {
+    prepared: atomic::Prepared,
+    original: Vec<u8>,
+}
+
+/// Rewrite one file, appending what would otherwise be printed to `out`.
+fn process_file_in_place(
+    file_path: &Path,
+    args: &Cli,
+    stripper: &Stripper,
+    snapshot: Option<&Snapshot>,
+    mirror: Option<&Mirror>,
+    out: &mut Output,
+) -> Result<Outcome> {
+    if is_stdin(file_path) {
+        anyhow::bail!("Standard input can only be processed as the only input");
+    }
+
+    let bytes = fs::read(file_path).with_context(|| format!("Could not read file `{}`", file_path.display()))?;
+    let Ok(content) = std::str::from_utf8(&bytes) else {
+        if let Some(mirror) = mirror.filter(|_| args.copy_other && !args.dry_run && !args.diff) {
+            mirror.copy(file_path, args.keep_mtime)?;
+            return Ok(Outcome::Skipped("not valid UTF-8, copied unchanged"));
+        }
+        return Ok(Outcome::Skipped("not valid UTF-8"));
+    };
+
+    let stripped = stripper.process(content);
+    if args.format == Format::Human {
+        write_warnings(file_path, content, &stripped.leftovers, &stripped.unused, &mut out.stderr);
+    } else {
+        out.findings = Findings::new(content, &stripped.matches, &stripped.edits, &stripped.leftovers, &stripped.unused);
+    }
+    let cleaned_content = stripped.text;
+    if let Some(mirror) = mirror.filter(|_| !args.dry_run && !args.diff) {
+        let target = mirror.write(file_path, cleaned_content.as_bytes(), args.keep_mtime)?;
+        if args.verbose {
+            let _ = writeln!(out.stdout, "Wrote: {}", target.display());
+        }
+        return Ok(if cleaned_content == content {
+            Outcome::Unchanged
+        } else {
+            Outcome::Changed
+        });
+    }
+    if cleaned_content == content {
+        return Ok(Outcome::Unchanged);
+    }
+
+    if args.diff {
+        out.stdout.push_str(&render_diff(file_path, content, &cleaned_content));
+        return Ok(Outcome::Changed);
+    }
 
     if args.dry_run {
         if args.verbose {
-            println!("[DRY RUN] Would process: {} ({} -> {} bytes)", 
-                     file_path.display(), content.len(), cleaned_content.len());
+            let _ = writeln!(out.stdout, "[DRY RUN] Would change: {} ({} -> {} bytes)",
+                             file_path.display(), content.len(), cleaned_content.len());
         } else {
-            println!("[DRY RUN] Would process: {}", file_path.display());
+            let _ = writeln!(out.stdout, "[DRY RUN] Would change: {}", file_path.display());
         }
-        return Ok(());
+        return Ok(Outcome::Changed);
+    }
+
+    let options = WriteOptions {
+        keep_mtime: args.keep_mtime,
+    };
+    let prepared = atomic::prepare(file_path, cleaned_content.as_bytes(), options)
+        .with_context(|| format!("Could not write to file `{}`", file_path.display()))?;
+    if args.transaction {
+        // Backups and the final rename wait until every file is prepared.
+        return Ok(Outcome::Staged(Box::new(Staged {
+            prepared,
+            original: bytes,
+        })));
     }
 
-    // Create backup if requested
-    if args.backup {
-        let backup_path = file_path.with_extension("md.bak");
+    back_up(file_path, args, snapshot, &mut out.stdout)?;
+    prepared
+        .commit()
+        .with_context(|| format!("Could not write to file `{}`", file_path.display()))?;
+
+    Ok(Outcome::Changed)
+}
+
+/// Create the backup requested for `file_path`, if any.
+fn back_up(file_path: &Path, args: &Cli, snapshot: Option<&Snapshot>, out: &mut String) -> Result<()> {
+    if let Some(snapshot) = snapshot {
+        let backup_path = snapshot.save(file_path)?;
+        if args.verbose {
+            let _ = writeln!(out, "Created backup: {}", backup_path.display());
+        }
+    } else if args.backup.backup {
+        let mut backup_path = file_path.as_os_str().to_owned();
+        backup_path.push(".bak");
+        let backup_path = PathBuf::from(backup_path);
         fs::copy(file_path, &backup_path)
             .with_context(|| format!("Could not create backup at `{}`", backup_path.display()))?;
         if args.verbose {
-            println!("Created backup: {}", backup_path.display());
+            let _ = writeln!(out, "Created backup: {}", backup_path.display());
         }
     }
+    Ok(())
+}
 
-    fs::write(file_path, cleaned_content)
-        .with_context(|| format!("Could not write to file `{}`", file_path.display()))?;
+/// Back up and put in place every staged file, or none of them: if one can't
+/// be replaced, the files replaced before it get their original contents
+/// back. Returns how many files were changed.
+fn commit_all(
+    staged: Vec<(&Path, Box<Staged>)>,
+    args: &Cli,
+    snapshot: Option<&Snapshot>,
+    failures: &mut Failures,
+) -> usize {
+    let mut out = Output::default();
+    for (file, _) in &staged {
+        if let Err(e) = back_up(file, args, snapshot, &mut out.stdout) {
+            if args.format == Format::Human {
+                out.print();
+            }
+            failures.push(file, format!("{:#}", e));
+            eprintln!("\nNo files were modified");
+            return 0;
+        }
+    }
+    if args.format == Format::Human {
+        out.print();
+    }
 
-    Ok(())
+    let mut transaction = Transaction::default();
+    let mut files = Vec::with_capacity(staged.len());
+    for (file, staged) in staged {
+        let Staged { prepared, original } = *staged;
+        transaction.push(prepared, original);
+        files.push(file);
+    }
+    match transaction.commit() {
+        Ok(committed) => committed,
+        Err(rolled_back) => {
+            failures.push(files[rolled_back.failed], format!("{:#}", rolled_back.error));
+            for (target, e) in &rolled_back.errors {
+                failures.push(target, format!("Could not roll back: {:#}", e));
+            }
+            eprintln!("\nRolled back {}", plural(rolled_back.restored, "file", "files"));
+            0
+        }
+    }
 }
 
-fn process_directory(args: &Cli) -> Result<()> {
-    let mut processed = 0;
-    let mut errors = 0;
+fn process_files(scan: &Scan, args: &Cli, loader: &mut Loader) -> Result<ExitCode> {
+    let files = &scan.files;
+    let mut changed = 0;
+    let mut unchanged = 0;
+    let mut skipped = 0;
+    let mut not_processed = 0;
+    let mut failures = Failures::default();
+    let mut report = Report::new(report::Command::Strip);
+    let human = args.format == Format::Human;
+    failures.extend_walk(&scan.errors);
+    if args.batch.fail_fast && !failures.is_empty() {
+        failures.report();
+        emit(args.format, report, &failures)?;
+        return Ok(ExitCode::from(EXIT_ERROR));
+    }
 
-    if args.verbose || args.dry_run {
-        println!("Scanning directory: {}\n", args.path.display());
+    if args.diff || !human {
+        // Keep stdout a clean patch or report; progress goes to stderr.
+    } else if args.verbose || args.dry_run {
+        println!("Processing {} files\n", files.len());
     }
 
-    for entry in WalkDir::new(&args.path).into_iter().filter_map(|e| e.ok()) {
-        if entry.path().extension() == Some(std::ffi::OsStr::new("md")) {
-            match process_file_in_place(entry.path(), args) {
-                Ok(_) => {
-                    if !args.dry_run && args.verbose {
-                        println!("✓ Processed: {}", entry.path().display());
-                    }
-                    processed += 1;
+    let snapshot = args.backup.snapshot()?;
+    let mirror = args
+        .out_dir
+        .as_deref()
+        .map(|dir| Mirror::new(dir, &args.input))
+        .transpose()?;
+    // Never process our own backups or output.
+    let generated = |file: &Path| {
+        snapshot.as_ref().is_some_and(|s| is_inside(file, s.root()))
+            || mirror.as_ref().is_some_and(|m| is_inside(file, &m.out_dir))
+    };
+    let work = files
+        .iter()
+        .filter(|file| !generated(file))
+        .map(|file| Ok((file, Stripper::new(loader.settings_for(file)?.options_for(file)))))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(mirror) = &mirror {
+        mirror.check_targets(work.iter().map(|(file, _)| file.as_path()))?;
+    }
+
+    // Results are gathered in path order and printed once every worker is done.
+    let results = args.batch.run(&work, |(file, stripper), out| {
+        process_file_in_place(file, args, stripper, snapshot.as_ref(), mirror.as_ref(), out)
+    })?;
+
+    let verbose = human && !args.dry_run && !args.diff && args.verbose;
+    let status = if args.dry_run { Status::WouldChange } else { Status::Changed };
+    let mut staged = Vec::new();
+    // The records of the staged files, settled once the transaction is.
+    let mut staged_records = Vec::new();
+    for ((file, _), result) in work.iter().zip(results) {
+        let Some((out, result)) = result else {
+            not_processed += 1;
+            report.files.push(FileRecord::new(file, Status::NotProcessed));
+            continue;
+        };
+        if human {
+            out.print();
+        }
+        let record = match result {
+            Ok(Outcome::Changed) => {
+                if verbose {
+                    println!("✓ Changed: {}", file.display());
                 }
-                Err(e) => {
-                    eprintln!("✗ Error processing {}: {}", entry.path().display(), e);
-                    errors += 1;
+                changed += 1;
+                FileRecord::new(file, status)
+            }
+            Ok(Outcome::Unchanged) => {
+                if verbose {
+                    println!("  Unchanged: {}", file.display());
                 }
+                unchanged += 1;
+                FileRecord::new(file, Status::Unchanged)
+            }
+            Ok(Outcome::Skipped(reason)) => {
+                if human {
+                    eprintln!("- Skipped {}: {}", file.display(), reason);
+                }
+                skipped += 1;
+                FileRecord::new(file, Status::Skipped).with_message(reason)
+            }
+            Ok(Outcome::Staged(prepared)) => {
+                if verbose {
+                    println!("✓ Prepared: {}", file.display());
+                }
+                staged.push((file.as_path(), prepared));
+                staged_records.push(report.files.len());
+                FileRecord::new(file, status)
+            }
+            Err(e) => {
+                if verbose {
+                    eprintln!("✗ Failed: {}", file.display());
+                }
+                let message = format!("{:#}", e);
+                failures.push(file, &message);
+                FileRecord::new(file, Status::Failed).with_message(message)
+            }
+        };
+        report.files.push(record.with_findings(out.findings));
+    }
+
+    if !staged.is_empty() {
+        let count = staged.len();
+        let committed = if failures.is_empty() && not_processed == 0 {
+            commit_all(staged, args, snapshot.as_ref(), &mut failures)
+        } else {
+            // Dropping the prepared files removes their temporary copies.
+            drop(staged);
+            eprintln!("\nNo files were modified because of the errors below");
+            0
+        };
+        changed += committed;
+        if committed < count {
+            // Nothing staged was kept, so every staged file counts as unchanged.
+            unchanged += count;
+            for &i in &staged_records {
+                let record = &mut report.files[i];
+                record.status = Status::Unchanged;
+                record.message = Some("not modified: the transaction did not complete".to_string());
             }
         }
     }
 
-    println!("\nCompleted: {} files processed, {} errors", processed, errors);
-    Ok(())
+    let mut copied = 0;
+    if let Some(mirror) = mirror.as_ref().filter(|_| args.copy_other && !args.dry_run && !args.diff) {
+        if not_processed == 0 {
+            let mut others = Vec::new();
+            for root in &mirror.roots {
+                let scan = walk::others(&root.path, &args.walk.options(), loader)?;
+                failures.extend_walk(&scan.errors);
+                others.extend(scan.files.into_iter().filter(|file| !generated(file)));
+            }
+            let results = args.batch.run(&others, |file, _| mirror.copy(file, args.keep_mtime))?;
+            for (file, result) in others.iter().zip(results) {
+                let record = match result {
+                    Some((_, Ok(_))) => {
+                        copied += 1;
+                        FileRecord::new(file, Status::Copied)
+                    }
+                    Some((_, Err(e))) => {
+                        let message = format!("{:#}", e);
+                        failures.push(file, &message);
+                        FileRecord::new(file, Status::Failed).with_message(message)
+                    }
+                    None => {
+                        not_processed += 1;
+                        FileRecord::new(file, Status::NotProcessed)
+                    }
+                };
+                report.files.push(record);
+            }
+        }
+    }
+
+    let summary = format!(
+        "Completed: {} changed, {} unchanged, {} skipped, {} errors",
+        changed,
+        unchanged,
+        skipped,
+        failures.len()
+    );
+    if args.diff {
+        eprintln!("{}", summary);
+    } else if human {
+        println!("\n{}", summary);
+    }
+    if let Some(mirror) = mirror.as_ref().filter(|_| human && copied > 0) {
+        println!("Copied {} to {}", plural(copied, "other file", "other files"), mirror.out_dir.display());
+    }
+    if not_processed > 0 {
+        eprintln!("Stopped at the first error; {} not processed", plural(not_processed, "file was", "files were"));
+    }
+    if let Some(snapshot) = &snapshot {
+        if let Some(id) = snapshot.id().filter(|_| human) {
+            println!("Backups saved as run {} in {}", id, snapshot.root().display());
+        }
+        let retention = args.backup.retention();
+        if retention.keep.is_some() || retention.max_age.is_some() {
+            for id in backup::prune(snapshot.root(), retention)? {
+                if human && args.verbose {
+                    println!("Removed old backup run {}", id);
+                }
+            }
+        }
+    }
+    failures.report();
+    emit(args.format, report, &failures)?;
+    Ok(exit_code(&failures, ExitCode::SUCCESS))
+}
+
+/// The deepest directory containing every one of `paths`.
+fn common_ancestor(paths: &[PathBuf]) -> Option<PathBuf> {
+    let (first, rest) = paths.split_first()?;
+    let mut ancestor = first.clone();
+    for path in rest {
+        while !path.starts_with(&ancestor) && ancestor.pop() {}
+    }
+    Some(ancestor)
+}
+
+/// Whether `path` is `dir` or below it, comparing absolute paths.
+fn is_inside(path: &Path, dir: &Path) -> bool {
+    std::path::absolute(path).is_ok_and(|path| path.starts_with(dir))
+}
+
+fn run_restore(args: &RestoreArgs) -> Result<ExitCode> {
+    let runs = backup::runs(&args.backup_dir)?;
+    if args.list {
+        for run in &runs {
+            println!("{}  {}", run.id, plural(run.files.len(), "file", "files"));
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let run = match &args.run {
+        Some(id) => runs
+            .iter()
+            .find(|run| &run.id == id)
+            .with_context(|| format!("No run `{}` in `{}`", id, args.backup_dir.display()))?,
+        None => runs
+            .last()
+            .with_context(|| format!("No backup runs in `{}`", args.backup_dir.display()))?,
+    };
+    let entries = run.select(&args.paths)?;
+    if entries.is_empty() {
+        anyhow::bail!("Run `{}` has no backups of the given paths", run.id);
+    }
+
+    let mut failures = Failures::default();
+    for entry in entries {
+        if args.dry_run {
+            println!("[DRY RUN] Would restore: {}", entry.path.display());
+            continue;
+        }
+        match run.restore(entry) {
+            Ok(()) => println!("✓ Restored: {}", entry.path.display()),
+            Err(e) => failures.push(&entry.path, format!("{:#}", e)),
+        }
+    }
+
+    failures.report();
+    Ok(exit_code(&failures, ExitCode::SUCCESS))
 }
\ No newline at end of file
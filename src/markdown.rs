@@ -0,0 +1,296 @@
+use std::{fmt, ops::Range, str::FromStr};
+
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+use serde::Deserialize;
+
+use crate::leftover::Structure;
+
+/// Markdown constructs whose prose can be left untouched.
+///
+/// Code, raw HTML, link destinations and front matter are always kept; these
+/// are the prose contexts that can additionally be skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Context {
+    Heading,
+    Link,
+    Image,
+    Table,
+    BlockQuote,
+    List,
+    Emphasis,
+    Footnote,
+}
+
+impl Context {
+    pub const ALL: [Context; 8] = [
+        Context::Heading,
+        Context::Link,
+        Context::Image,
+        Context::Table,
+        Context::BlockQuote,
+        Context::List,
+        Context::Emphasis,
+        Context::Footnote,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Context::Heading => "heading",
+            Context::Link => "link",
+            Context::Image => "image",
+            Context::Table => "table",
+            Context::BlockQuote => "block-quote",
+            Context::List => "list",
+            Context::Emphasis => "emphasis",
+            Context::Footnote => "footnote",
+        }
+    }
+
+    fn of_start(tag: &Tag) -> Option<Context> {
+        match tag {
+            Tag::Heading { .. } => Some(Context::Heading),
+            Tag::Link { .. } => Some(Context::Link),
+            Tag::Image { .. } => Some(Context::Image),
+            Tag::Table(_) => Some(Context::Table),
+            Tag::BlockQuote(_) => Some(Context::BlockQuote),
+            Tag::List(_) => Some(Context::List),
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough => Some(Context::Emphasis),
+            Tag::FootnoteDefinition(_) => Some(Context::Footnote),
+            _ => None,
+        }
+    }
+
+    fn of_end(tag: &TagEnd) -> Option<Context> {
+        match tag {
+            TagEnd::Heading(_) => Some(Context::Heading),
+            TagEnd::Link => Some(Context::Link),
+            TagEnd::Image => Some(Context::Image),
+            TagEnd::Table => Some(Context::Table),
+            TagEnd::BlockQuote(_) => Some(Context::BlockQuote),
+            TagEnd::List(_) => Some(Context::List),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => Some(Context::Emphasis),
+            TagEnd::FootnoteDefinition => Some(Context::Footnote),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Context {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Context::ALL
+            .into_iter()
+            .find(|context| context.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = Context::ALL.iter().map(|c| c.name()).collect();
+                format!("unknown Markdown context `{}` (expected one of: {})", s, names.join(", "))
+            })
+    }
+}
+
+fn parser_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+}
+
+/// Byte ranges of the prose text nodes in `source`, in document order.
+///
+/// Code blocks, inline code, raw HTML, autolinks, link destinations and front
+/// matter never show up here, nor does prose inside any of the `skip`
+/// contexts. Adjacent text nodes are merged into one range.
+pub(crate) fn prose_ranges(source: &str, skip: &[Context]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut verbatim_depth = 0usize;
+    let mut skipped_depth = 0usize;
+
+    for (event, range) in Parser::new_ext(source, parser_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_) | Tag::MetadataBlock(_) | Tag::HtmlBlock) => {
+                verbatim_depth += 1;
+            }
+            Event::Start(Tag::Link {
+                link_type: LinkType::Autolink | LinkType::Email,
+                ..
+            }) => verbatim_depth += 1,
+            Event::End(TagEnd::CodeBlock | TagEnd::MetadataBlock(_) | TagEnd::HtmlBlock) => {
+                verbatim_depth -= 1;
+            }
+            Event::End(TagEnd::Link) if verbatim_depth > 0 => verbatim_depth -= 1,
+            Event::Start(tag) if Context::of_start(&tag).is_some_and(|c| skip.contains(&c)) => {
+                skipped_depth += 1;
+            }
+            Event::End(tag) if Context::of_end(&tag).is_some_and(|c| skip.contains(&c)) => {
+                skipped_depth -= 1;
+            }
+            Event::Text(_) if verbatim_depth == 0 && skipped_depth == 0 => {
+                // Escapes and entity references can produce nodes whose
+                // offsets do not advance; never hand out overlapping ranges.
+                match ranges.last_mut() {
+                    Some(last) if range.start < last.end => {}
+                    Some(last) if range.start == last.end => last.end = range.end,
+                    _ => ranges.push(range),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ranges
+}
+
+/// Byte ranges of the raw HTML in `source`: HTML blocks and inline HTML,
+/// but not HTML shown inside code.
+pub(crate) fn html_ranges(source: &str) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (event, range) in Parser::new_ext(source, parser_options()).into_offset_iter() {
+        if !matches!(event, Event::Start(Tag::HtmlBlock) | Event::InlineHtml(_)) {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => ranges.push(range),
+        }
+    }
+    ranges
+}
+
+/// A heading, link, list item or table cell, with what it contains.
+#[derive(Debug, Clone)]
+pub(crate) struct Element {
+    pub structure: Structure,
+    pub range: Range<usize>,
+    /// Every text node inside, including those of nested elements.
+    pub texts: Vec<Range<usize>>,
+    /// Whether it holds anything besides text, such as code, an image or raw
+    /// HTML. Nested lists are looked into like emphasis is, so an item whose
+    /// sub-items are all empty is empty too.
+    pub other: bool,
+}
+
+/// Every element that stripping could leave empty, in document order.
+pub(crate) fn elements(source: &str) -> Vec<Element> {
+    let mut elements: Vec<Element> = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+
+    for (event, range) in Parser::new_ext(source, parser_options()).into_offset_iter() {
+        let structure = match &event {
+            Event::Start(Tag::Heading { .. }) => Some(Structure::Heading),
+            Event::Start(Tag::Link {
+                link_type: LinkType::Autolink | LinkType::Email,
+                ..
+            }) => None,
+            Event::Start(Tag::Link { .. }) => Some(Structure::Link),
+            Event::Start(Tag::Item) => Some(Structure::ListItem),
+            Event::Start(Tag::TableCell) => Some(Structure::TableCell),
+            _ => None,
+        };
+
+        if let Some(structure) = structure {
+            open.push(elements.len());
+            elements.push(Element {
+                structure,
+                range,
+                texts: Vec::new(),
+                other: false,
+            });
+            continue;
+        }
+
+        match event {
+            Event::End(TagEnd::Heading(_) | TagEnd::Item | TagEnd::TableCell) => {
+                open.pop();
+            }
+            Event::End(TagEnd::Link) => {
+                // Autolinks were never pushed.
+                if open
+                    .last()
+                    .is_some_and(|&i| elements[i].structure == Structure::Link && elements[i].range == range)
+                {
+                    open.pop();
+                }
+            }
+            Event::Text(_) => {
+                for &i in &open {
+                    elements[i].texts.push(range.clone());
+                }
+            }
+            Event::Start(
+                Tag::Paragraph
+                | Tag::List(_)
+                | Tag::Emphasis
+                | Tag::Strong
+                | Tag::Strikethrough
+                | Tag::Link { .. },
+            )
+            | Event::End(_)
+            | Event::SoftBreak
+            | Event::HardBreak
+            | Event::TaskListMarker(_) => {}
+            _ => {
+                for &i in &open {
+                    elements[i].other = true;
+                }
+            }
+        }
+    }
+
+    elements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Emojis outside prose survive stripping; the ones in prose go.
+    #[test]
+    fn only_prose_is_touched() {
+        for (input, expected) in [
+            ("```\n🚀 code\n```\n", "```\n🚀 code\n```\n"),
+            ("~~~rust\nlet x = \"🚀\";\n~~~\n", "~~~rust\nlet x = \"🚀\";\n~~~\n"),
+            ("Text 🎉\n\n    🚀 code\n", "Text\n\n    🚀 code\n"),
+            ("Run `🚀` now 🎉\n", "Run `🚀` now\n"),
+            ("Run `` `🚀` `` now\n", "Run `` `🚀` `` now\n"),
+            ("[docs 🚀](https://x/🚀 \"title 🎉\")\n", "[docs](https://x/🚀 \"title 🎉\")\n"),
+            ("[a 🚀][r]\n\n[r]: https://x/🚀 \"🎉\"\n", "[a][r]\n\n[r]: https://x/🚀 \"🎉\"\n"),
+            ("![🚀 logo](x/🚀.png \"🎉\")\n", "![logo](x/🚀.png \"🎉\")\n"),
+            ("<https://x/🚀> 🎉\n", "<https://x/🚀>\n"),
+            ("<mailto:me@example.com?subject=🚀> 🎉\n", "<mailto:me@example.com?subject=🚀>\n"),
+            ("<kbd title=\"🚀\">Ctrl</kbd> 🎉\n", "<kbd title=\"🚀\">Ctrl</kbd>\n"),
+            ("<div title=\"🚀\">\n🎉\n</div>\n", "<div title=\"🚀\">\n🎉\n</div>\n"),
+            ("---\ntitle: 🚀\n---\n\nText 🎉\n", "---\ntitle: 🚀\n---\n\nText\n"),
+            ("&#x1F680; and 🎉 &amp; &#128640;\n", "&#x1F680; and &amp; &#128640;\n"),
+            ("\\*not emphasis\\* 🚀\n", "\\*not emphasis\\*\n"),
+            ("Line 🚀  \nnext\n", "Line  \nnext\n"),
+        ] {
+            assert_eq!(crate::strip(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn prose_ranges_skip_code_and_urls() {
+        let source = "Text with `code`, [a link](https://x) and <https://y>.\n";
+        let texts: Vec<_> = prose_ranges(source, &[]).into_iter().map(|r| &source[r]).collect();
+        assert_eq!(texts, ["Text with ", ", ", "a link", " and ", "."]);
+    }
+
+    #[test]
+    fn prose_ranges_skip_contexts() {
+        let source = "# Title 🚀\n\nText 🎉\n";
+        let texts: Vec<_> = prose_ranges(source, &[Context::Heading])
+            .into_iter()
+            .map(|r| &source[r])
+            .collect();
+        assert_eq!(texts, ["Text 🎉"]);
+    }
+}
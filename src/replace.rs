@@ -0,0 +1,102 @@
+use std::{fmt, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::emoji;
+
+/// What a removed emoji sequence is replaced with.
+///
+/// Names and shortcodes come from the bundled CLDR and GitHub emoji data.
+/// Sequences missing from that data fall back to their codepoints.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Replacement {
+    /// Remove the sequence, collapsing the space around it.
+    #[default]
+    Delete,
+    /// Replace every sequence with a fixed string (`text:<string>`).
+    Text(String),
+    /// GitHub-style shortcode, e.g. `:rocket:`.
+    Shortcode,
+    /// CLDR short name, e.g. `check mark button`.
+    Name,
+    /// A template with `{name}`, `{codepoints}` and `{shortcode}` placeholders
+    /// (`template:<template>`).
+    Template(String),
+}
+
+impl Replacement {
+    /// The text that takes the place of `sequence`.
+    pub fn render(&self, sequence: &str) -> String {
+        let data = emoji::lookup(sequence);
+        let name = || data.map_or_else(|| emoji::codepoints(sequence), |e| e.name().to_string());
+        // Skin-tone variants have no shortcode of their own; use the base emoji's.
+        let toneless: String = sequence
+            .chars()
+            .filter(|c| !('\u{1F3FB}'..='\u{1F3FF}').contains(c))
+            .collect();
+        let found_shortcode = data
+            .and_then(|e| e.shortcode())
+            .or_else(|| emoji::lookup(&toneless).and_then(|e| e.shortcode()));
+        let shortcode = || found_shortcode.map_or_else(|| emoji::codepoints(sequence), str::to_string);
+
+        match self {
+            Replacement::Delete => String::new(),
+            Replacement::Text(text) => text.clone(),
+            Replacement::Shortcode => match found_shortcode {
+                Some(shortcode) => format!(":{}:", shortcode),
+                None => emoji::codepoints(sequence),
+            },
+            Replacement::Name => name(),
+            Replacement::Template(template) => template
+                .replace("{name}", &name())
+                .replace("{codepoints}", &emoji::codepoints(sequence))
+                .replace("{shortcode}", &shortcode()),
+        }
+    }
+}
+
+impl FromStr for Replacement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "delete" => Ok(Replacement::Delete),
+            "shortcode" => Ok(Replacement::Shortcode),
+            "name" => Ok(Replacement::Name),
+            _ => {
+                if let Some(text) = s.strip_prefix("text:") {
+                    Ok(Replacement::Text(text.to_string()))
+                } else if let Some(template) = s.strip_prefix("template:") {
+                    Ok(Replacement::Template(template.to_string()))
+                } else {
+                    Err(format!(
+                        "unknown replacement mode `{}` (expected `delete`, `shortcode`, `name`, \
+                         `text:<string>` or `template:<template>`)",
+                        s
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Replacement {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Replacement::Delete => f.write_str("delete"),
+            Replacement::Text(text) => write!(f, "text:{}", text),
+            Replacement::Shortcode => f.write_str("shortcode"),
+            Replacement::Name => f.write_str("name"),
+            Replacement::Template(template) => write!(f, "template:{}", template),
+        }
+    }
+}
@@ -0,0 +1,456 @@
+//! Machine-readable reports of a run, as one JSON document, as JSON Lines
+//! or as a SARIF log.
+//!
+//! Every JSON document and line carries `"version"`, the [`VERSION`] of
+//! the schema; it is raised whenever a field changes meaning or goes away,
+//! not when one is added.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, Write},
+    ops::Range,
+    path::Path,
+    str::FromStr,
+};
+
+use serde::Serialize;
+
+use crate::{Edit, Leftover, Locator, Match, Suppression};
+
+mod sarif;
+
+/// Version of the report schema.
+pub const VERSION: u32 = 1;
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Text meant for people; the default.
+    #[default]
+    Human,
+    /// One JSON document once the run is over.
+    Json,
+    /// One JSON object per line: a line per match, warning, file and
+    /// error, then the summary.
+    Jsonl,
+    /// A SARIF 2.1.0 log, for code-scanning tools; `check` only.
+    Sarif,
+}
+
+impl Format {
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Human => "human",
+            Format::Json => "json",
+            Format::Jsonl => "jsonl",
+            Format::Sarif => "sarif",
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "jsonl" => Ok(Format::Jsonl),
+            "sarif" => Ok(Format::Sarif),
+            _ => Err(format!("unknown format `{}` (expected `human`, `json`, `jsonl` or `sarif`)", s)),
+        }
+    }
+}
+
+/// The command a report is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Command {
+    Strip,
+    Check,
+}
+
+/// Where something is in a file: a byte range, and the 1-based line and
+/// column (in characters) it starts and ends at. The end is exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl Span {
+    pub fn new(locator: &mut Locator, range: &Range<usize>) -> Self {
+        let (line, column) = locator.locate(range.start);
+        let (end_line, end_column) = locator.locate(range.end);
+        Self {
+            start: range.start,
+            end: range.end,
+            line,
+            column,
+            end_line,
+            end_column,
+        }
+    }
+}
+
+/// An edit to a file: the text at `span` is replaced with `text`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Fix {
+    pub span: Span,
+    /// Empty for a deletion.
+    pub text: String,
+}
+
+/// An emoji sequence that was, or would be, removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MatchRecord {
+    pub sequence: String,
+    /// `U+XXXX` for each codepoint of the sequence.
+    pub codepoints: Vec<String>,
+    pub span: Span,
+    /// This sequence's part of the edit that removes it: the sequence and the
+    /// whitespace repair up to the next sequence removed by the same edit.
+    /// The fixes of a file never overlap, and applying all of them gives
+    /// the stripped text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Fix>,
+}
+
+impl MatchRecord {
+    /// The record of `m`, with `fix`, its part of the edit that removes it.
+    pub fn new(locator: &mut Locator, m: &Match, fix: Option<&Edit>) -> Self {
+        Self {
+            sequence: m.sequence.clone(),
+            codepoints: m.codepoints().split(' ').map(str::to_string).collect(),
+            span: Span::new(locator, &m.range),
+            fix: fix.map(|e| Fix {
+                span: Span::new(locator, &e.range),
+                text: e.text.clone(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WarningKind {
+    /// A Markdown element that stripping left empty; see [`Leftover`].
+    EmptyElement,
+    /// A suppression comment that kept no emoji.
+    UnusedSuppression,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WarningRecord {
+    pub kind: WarningKind,
+    pub message: String,
+    pub span: Span,
+}
+
+/// What was found in one file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Findings {
+    pub matches: Vec<MatchRecord>,
+    /// In document order.
+    pub warnings: Vec<WarningRecord>,
+}
+
+impl Findings {
+    pub fn new(text: &str, matches: &[Match], edits: &[Edit], leftovers: &[Leftover], unused: &[Suppression]) -> Self {
+        let mut locator = Locator::new(text);
+        // Both are in document order, so one cursor finds each match's edit.
+        let mut next = 0;
+        let covers = |e: &Edit, m: &Match| e.range.start <= m.range.start && m.range.end <= e.range.end;
+        let matches = matches
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                while edits.get(next).is_some_and(|e| e.range.end < m.range.end) {
+                    next += 1;
+                }
+                let fix = edits.get(next).filter(|e| covers(e, m)).map(|e| {
+                    // An edit removing several sequences is split between
+                    // them at the start of each: the first takes what comes
+                    // before it, the last what comes after and the new text.
+                    let first = i == 0 || !covers(e, &matches[i - 1]);
+                    let following = matches.get(i + 1).filter(|n| covers(e, n));
+                    Edit {
+                        range: if first { e.range.start } else { m.range.start }
+                            ..following.map_or(e.range.end, |n| n.range.start),
+                        text: if following.is_some() { String::new() } else { e.text.clone() },
+                    }
+                });
+                MatchRecord::new(&mut locator, m, fix.as_ref())
+            })
+            .collect();
+        let mut found: Vec<(WarningKind, String, &Range<usize>)> = leftovers
+            .iter()
+            .map(|l| (WarningKind::EmptyElement, l.to_string(), &l.range))
+            .chain(unused.iter().map(|s| (WarningKind::UnusedSuppression, s.to_string(), &s.range)))
+            .collect();
+        found.sort_by_key(|(_, _, range)| range.start);
+        let warnings = found
+            .into_iter()
+            .map(|(kind, message, range)| WarningRecord {
+                kind,
+                message,
+                span: Span::new(&mut locator, range),
+            })
+            .collect();
+        Self { matches, warnings }
+    }
+}
+
+/// What happened to one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    /// Rewritten, or written to the output directory.
+    Changed,
+    /// Would be rewritten without `--dry-run`.
+    WouldChange,
+    Unchanged,
+    /// Not processed, for the reason in `message`.
+    Skipped,
+    /// Copied unchanged to the output directory.
+    Copied,
+    /// `check` found emojis.
+    Found,
+    /// `check` found none.
+    Clean,
+    /// Could not be processed; `message` says why.
+    Failed,
+    /// Never started because an earlier file failed with `--fail-fast`.
+    NotProcessed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileRecord {
+    pub path: String,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub findings: Findings,
+}
+
+impl FileRecord {
+    pub fn new(path: &Path, status: Status) -> Self {
+        Self {
+            path: path.to_string_lossy().into_owned(),
+            status,
+            message: None,
+            findings: Findings::default(),
+        }
+    }
+
+    pub fn with_message(mut self, message: impl fmt::Display) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    pub fn with_findings(mut self, findings: Findings) -> Self {
+        self.findings = findings;
+        self
+    }
+}
+
+/// A path that failed: a file, or a directory that could not be scanned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorRecord {
+    pub path: String,
+    pub message: String,
+}
+
+impl ErrorRecord {
+    pub fn new(path: &Path, message: impl fmt::Display) -> Self {
+        Self {
+            path: path.to_string_lossy().into_owned(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Totals over a whole run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    pub command: Command,
+    pub files: usize,
+    pub matches: usize,
+    pub warnings: usize,
+    pub errors: usize,
+    /// How many files ended with each status; statuses no file has are
+    /// left out.
+    pub statuses: BTreeMap<Status, usize>,
+}
+
+/// Everything a run reports, written out once it is over.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub command: Command,
+    /// In the order the files were processed.
+    pub files: Vec<FileRecord>,
+    /// Every path that failed, including the files whose status is `failed`.
+    pub errors: Vec<ErrorRecord>,
+}
+
+#[derive(Serialize)]
+struct Document<'a> {
+    version: u32,
+    command: Command,
+    files: &'a [FileRecord],
+    errors: &'a [ErrorRecord],
+    summary: Summary,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum Event<'a> {
+    Match {
+        path: &'a str,
+        #[serde(flatten)]
+        record: &'a MatchRecord,
+    },
+    Warning {
+        path: &'a str,
+        #[serde(flatten)]
+        record: &'a WarningRecord,
+    },
+    /// Follows the file's matches and warnings, with their counts.
+    File {
+        path: &'a str,
+        status: Status,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<&'a str>,
+        matches: usize,
+        warnings: usize,
+    },
+    Error(&'a ErrorRecord),
+    Summary(&'a Summary),
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+    version: u32,
+    #[serde(flatten)]
+    event: Event<'a>,
+}
+
+impl Report {
+    pub fn new(command: Command) -> Self {
+        Self {
+            command,
+            files: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn summary(&self) -> Summary {
+        let mut statuses = BTreeMap::new();
+        for file in &self.files {
+            *statuses.entry(file.status).or_insert(0) += 1;
+        }
+        Summary {
+            command: self.command,
+            files: self.files.len(),
+            matches: self.files.iter().map(|f| f.findings.matches.len()).sum(),
+            warnings: self.files.iter().map(|f| f.findings.warnings.len()).sum(),
+            errors: self.errors.len(),
+            statuses,
+        }
+    }
+
+    /// Write the report in `format`, which must not be [`Format::Human`].
+    pub fn write(&self, format: Format, mut out: impl Write) -> io::Result<()> {
+        match format {
+            Format::Human => Err(io::Error::new(io::ErrorKind::InvalidInput, "no report for the human format")),
+            Format::Sarif => sarif::write(self, out),
+            Format::Json => {
+                let document = Document {
+                    version: VERSION,
+                    command: self.command,
+                    files: &self.files,
+                    errors: &self.errors,
+                    summary: self.summary(),
+                };
+                serde_json::to_writer_pretty(&mut out, &document)?;
+                writeln!(out)
+            }
+            Format::Jsonl => {
+                let mut line = |event: Event| -> io::Result<()> {
+                    serde_json::to_writer(&mut out, &Line { version: VERSION, event })?;
+                    writeln!(out)
+                };
+                for file in &self.files {
+                    for record in &file.findings.matches {
+                        line(Event::Match { path: &file.path, record })?;
+                    }
+                    for record in &file.findings.warnings {
+                        line(Event::Warning { path: &file.path, record })?;
+                    }
+                    line(Event::File {
+                        path: &file.path,
+                        status: file.status,
+                        message: file.message.as_deref(),
+                        matches: file.findings.matches.len(),
+                        warnings: file.findings.warnings.len(),
+                    })?;
+                }
+                for error in &self.errors {
+                    line(Event::Error(error))?;
+                }
+                line(Event::Summary(&self.summary()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Options, Policies, Policy, Stripper};
+
+    #[test]
+    fn fixes_never_overlap_and_rebuild_the_stripped_text() {
+        let stripper = Stripper::new(Options {
+            leftovers: Policies {
+                heading: Policy::Delete,
+                ..Policies::default()
+            },
+            ..Options::default()
+        });
+        for text in [
+            "Hi 🚀🎉 there\n",
+            "Hi 🚀 🎉 there\n",
+            "foo🚀🎉bar\n",
+            "Done 🎉 🎉!\n",
+            "## 🎉 🚀\n\nText ✨\n",
+        ] {
+            let stripped = stripper.process(text);
+            let findings = Findings::new(text, &stripped.matches, &stripped.edits, &[], &[]);
+            let mut out = String::new();
+            let mut last = 0;
+            for m in &findings.matches {
+                let fix = m.fix.as_ref().unwrap();
+                assert!(last <= fix.span.start, "overlapping fixes in {:?}", text);
+                assert!(fix.span.start <= m.span.start && m.span.end <= fix.span.end, "{:?}", text);
+                out.push_str(&text[last..fix.span.start]);
+                out.push_str(&fix.text);
+                last = fix.span.end;
+            }
+            out.push_str(&text[last..]);
+            assert_eq!(out, stripped.text, "{:?}", text);
+        }
+    }
+}
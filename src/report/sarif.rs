@@ -0,0 +1,150 @@
+//! SARIF 2.1.0 logs, as read by code-scanning dashboards.
+//!
+//! Every match becomes a result of the `emoji` rule with a fix holding the
+//! edit stripping would make; warnings become results of their own rules,
+//! and failed paths become tool execution notifications.
+
+use std::io::{self, Write};
+
+use serde_json::{json, Value};
+
+use super::{FileRecord, Report, Span, WarningKind};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The rules, in the order `ruleIndex` refers to.
+const RULES: [(&str, &str, &str); 3] = [
+    ("emoji", "warning", "Emoji in prose"),
+    ("empty-element", "warning", "Markdown element left empty by stripping"),
+    ("unused-suppression", "note", "Suppression comment that keeps no emoji"),
+];
+
+pub(super) fn write(report: &Report, mut out: impl Write) -> io::Result<()> {
+    let rules: Vec<Value> = RULES
+        .iter()
+        .map(|(id, level, description)| {
+            json!({
+                "id": id,
+                "shortDescription": { "text": description },
+                "defaultConfiguration": { "level": level },
+            })
+        })
+        .collect();
+
+    let results: Vec<Value> = report.files.iter().flat_map(results).collect();
+    let notifications: Vec<Value> = report
+        .errors
+        .iter()
+        .map(|error| {
+            json!({
+                "level": "error",
+                "message": { "text": error.message },
+                "locations": [{ "physicalLocation": { "artifactLocation": { "uri": uri(&error.path) } } }],
+            })
+        })
+        .collect();
+
+    let log = json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "remoji",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "invocations": [{
+                "executionSuccessful": report.errors.is_empty(),
+                "toolExecutionNotifications": notifications,
+            }],
+            // Columns count characters, like the other formats.
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    });
+    serde_json::to_writer_pretty(&mut out, &log)?;
+    writeln!(out)
+}
+
+/// The results for one file: its matches, then its warnings.
+fn results(file: &FileRecord) -> Vec<Value> {
+    let uri = uri(&file.path);
+    let matches = file.findings.matches.iter().map(|m| {
+        let mut result = result(0, &format!("Emoji {} ({})", m.sequence, m.codepoints.join(" ")), &uri, &m.span);
+        if let Some(fix) = &m.fix {
+            let description = if fix.text.trim().is_empty() {
+                format!("Remove {}", m.sequence)
+            } else {
+                format!("Replace {} with {}", m.sequence, fix.text)
+            };
+            result["fixes"] = json!([{
+                "description": { "text": description },
+                "artifactChanges": [{
+                    "artifactLocation": { "uri": uri },
+                    "replacements": [{
+                        "deletedRegion": region(&fix.span),
+                        "insertedContent": { "text": fix.text },
+                    }],
+                }],
+            }]);
+        }
+        result
+    });
+    let warnings = file.findings.warnings.iter().map(|w| {
+        let rule = match w.kind {
+            WarningKind::EmptyElement => 1,
+            WarningKind::UnusedSuppression => 2,
+        };
+        result(rule, &w.message, &uri, &w.span)
+    });
+    matches.chain(warnings).collect()
+}
+
+fn result(rule: usize, message: &str, uri: &str, span: &Span) -> Value {
+    let (id, level, _) = RULES[rule];
+    json!({
+        "ruleId": id,
+        "ruleIndex": rule,
+        "level": level,
+        "message": { "text": message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": { "uri": uri },
+                "region": region(span),
+            },
+        }],
+    })
+}
+
+fn region(span: &Span) -> Value {
+    json!({
+        "startLine": span.line,
+        "startColumn": span.column,
+        "endLine": span.end_line,
+        "endColumn": span.end_column,
+    })
+}
+
+/// `path` as a URI reference: relative paths stay relative (to the
+/// directory remoji ran in), absolute ones become `file://` URIs.
+fn uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut uri = String::with_capacity(path.len());
+    if path.starts_with('/') {
+        uri.push_str("file://");
+    } else if path.as_bytes().get(1) == Some(&b':') {
+        // A Windows drive letter.
+        uri.push_str("file:///");
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
@@ -0,0 +1,271 @@
+//! `<!-- remoji-disable -->` and related comments that keep emojis in place.
+//!
+//! A comment is recognised when its first word is one of the directives
+//! below; anything after it, such as a reason, is ignored:
+//!
+//! ```markdown
+//! <!-- remoji-disable-next-line: the table shows how emojis render -->
+//! | ✅ | done |
+//! ```
+
+use std::{fmt, ops::Range};
+
+use crate::Match;
+
+/// What a suppression comment asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Directive {
+    /// Keep emojis until the next `remoji-enable`, or the end of the file.
+    Disable,
+    /// End a `remoji-disable` region.
+    Enable,
+    /// Keep emojis on the line after the comment.
+    DisableNextLine,
+    /// Keep every emoji in the file.
+    DisableFile,
+}
+
+impl Directive {
+    pub const ALL: [Directive; 4] = [
+        Directive::Disable,
+        Directive::Enable,
+        Directive::DisableNextLine,
+        Directive::DisableFile,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Directive::Disable => "remoji-disable",
+            Directive::Enable => "remoji-enable",
+            Directive::DisableNextLine => "remoji-disable-next-line",
+            Directive::DisableFile => "remoji-disable-file",
+        }
+    }
+
+    /// The directive of an HTML comment's body, if it is one.
+    fn parse(body: &str) -> Option<Self> {
+        let word = body
+            .trim_start()
+            .split(|c: char| c.is_whitespace() || c == ':')
+            .next()?;
+        Directive::ALL.into_iter().find(|d| d.name() == word)
+    }
+}
+
+impl fmt::Display for Directive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A suppression comment in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    pub directive: Directive,
+    /// Byte range of the whole comment in the input text.
+    pub range: Range<usize>,
+}
+
+/// Shown for a suppression that kept no emoji.
+impl fmt::Display for Suppression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.directive {
+            Directive::Enable => write!(f, "`{}` without a `{}` before it", self.directive, Directive::Disable),
+            _ => write!(f, "unused `{}`: it suppresses no emojis", self.directive),
+        }
+    }
+}
+
+/// The suppression comments of one text and the text each one covers.
+#[derive(Debug, Default)]
+pub(crate) struct Suppressions {
+    /// `None` for comments that cover nothing: an `enable` with no region
+    /// to end, or a `disable` inside a region that is already disabled.
+    scopes: Vec<(Suppression, Option<Range<usize>>)>,
+}
+
+impl Suppressions {
+    /// Read the suppression comments in `html`, the raw HTML ranges of the
+    /// Markdown `text`.
+    pub(crate) fn parse(text: &str, html: &[Range<usize>]) -> Self {
+        let mut scopes = Vec::new();
+        let mut open: Option<usize> = None;
+
+        for comment in comments(text, html) {
+            let Some(directive) = Directive::parse(&text[comment.start + 4..comment.end - 3]) else {
+                continue;
+            };
+            let suppression = Suppression {
+                directive,
+                range: comment.clone(),
+            };
+            match directive {
+                Directive::Disable if open.is_some() => scopes.push((suppression, None)),
+                Directive::Disable => {
+                    open = Some(scopes.len());
+                    scopes.push((suppression, Some(comment.end..text.len())));
+                }
+                Directive::Enable => match open.take() {
+                    Some(i) => {
+                        if let Some(scope) = &mut scopes[i].1 {
+                            scope.end = comment.start;
+                        }
+                    }
+                    None => scopes.push((suppression, None)),
+                },
+                Directive::DisableNextLine => {
+                    let start = text[comment.end..].find('\n').map_or(text.len(), |i| comment.end + i + 1);
+                    let end = text[start..].find('\n').map_or(text.len(), |i| start + i);
+                    scopes.push((suppression, Some(start..end)));
+                }
+                Directive::DisableFile => scopes.push((suppression, Some(0..text.len()))),
+            }
+        }
+
+        Self { scopes }
+    }
+
+    /// Remove the suppressed matches, returning the comments that suppressed
+    /// none, in document order.
+    pub(crate) fn apply(&self, matches: &mut Vec<Match>) -> Vec<Suppression> {
+        if self.scopes.is_empty() {
+            return Vec::new();
+        }
+
+        let mut used = vec![false; self.scopes.len()];
+        matches.retain(|m| {
+            let mut kept = true;
+            for (i, (_, scope)) in self.scopes.iter().enumerate() {
+                if scope.as_ref().is_some_and(|s| s.contains(&m.range.start)) {
+                    used[i] = true;
+                    kept = false;
+                }
+            }
+            kept
+        });
+
+        self.scopes
+            .iter()
+            .zip(used)
+            .filter(|(_, used)| !used)
+            .map(|((suppression, _), _)| suppression.clone())
+            .collect()
+    }
+}
+
+/// Byte ranges of the `<!-- ... -->` comments inside `html`.
+fn comments<'a>(text: &'a str, html: &'a [Range<usize>]) -> impl Iterator<Item = Range<usize>> + 'a {
+    html.iter().flat_map(move |range| {
+        let mut pos = range.start;
+        std::iter::from_fn(move || {
+            let start = pos + text[pos..range.end].find("<!--")?;
+            let end = start + 4 + text[start + 4..range.end].find("-->")? + 3;
+            pos = end;
+            Some(start..end)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Stripper;
+
+    /// `(input, output, unused directives)`, in both strip and check mode.
+    fn check(cases: &[(&str, &str, &[Directive])]) {
+        let stripper = Stripper::default();
+        for &(input, expected, unused) in cases {
+            let stripped = stripper.process(input);
+            assert_eq!(stripped.text, expected, "{:?}", input);
+            let found: Vec<_> = stripper.check(input).unused.iter().map(|s| s.directive).collect();
+            assert_eq!(found, unused, "{:?}", input);
+            assert_eq!(stripped.unused, stripper.check(input).unused, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn disable_and_enable() {
+        check(&[
+            (
+                "A 🚀\n<!-- remoji-disable -->\nB 🚀\n<!-- remoji-enable -->\nC 🚀\n",
+                "A\n<!-- remoji-disable -->\nB 🚀\n<!-- remoji-enable -->\nC\n",
+                &[],
+            ),
+            // Without an `enable`, up to the end of the file.
+            (
+                "A 🚀\n<!-- remoji-disable: demo of the icons -->\nB 🚀\n\nC 🚀\n",
+                "A\n<!-- remoji-disable: demo of the icons -->\nB 🚀\n\nC 🚀\n",
+                &[],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn disable_next_line() {
+        check(&[
+            (
+                "<!-- remoji-disable-next-line -->\nA 🚀\nB 🚀\n",
+                "<!-- remoji-disable-next-line -->\nA 🚀\nB\n",
+                &[],
+            ),
+            (
+                "<!-- remoji-disable-next-line -->\r\nA 🚀\r\nB 🚀\r\n",
+                "<!-- remoji-disable-next-line -->\r\nA 🚀\r\nB\r\n",
+                &[],
+            ),
+            // At the end of the file, there is no line to keep.
+            ("A 🚀\n<!-- remoji-disable-next-line -->", "A\n<!-- remoji-disable-next-line -->", &[Directive::DisableNextLine]),
+            ("A 🚀\n<!-- remoji-disable-next-line -->\n", "A\n<!-- remoji-disable-next-line -->\n", &[Directive::DisableNextLine]),
+        ]);
+    }
+
+    #[test]
+    fn disable_file() {
+        check(&[
+            ("A 🚀\n\n<!-- remoji-disable-file -->\n\nB 🎉\n", "A 🚀\n\n<!-- remoji-disable-file -->\n\nB 🎉\n", &[]),
+            ("Plain\n\n<!-- remoji-disable-file -->\n", "Plain\n\n<!-- remoji-disable-file -->\n", &[Directive::DisableFile]),
+        ]);
+    }
+
+    #[test]
+    fn nested_disable_and_stray_enable() {
+        check(&[
+            (
+                "<!-- remoji-disable -->\nA 🚀\n<!-- remoji-disable -->\nB 🚀\n<!-- remoji-enable -->\nC 🚀\n",
+                "<!-- remoji-disable -->\nA 🚀\n<!-- remoji-disable -->\nB 🚀\n<!-- remoji-enable -->\nC\n",
+                &[Directive::Disable],
+            ),
+            ("<!-- remoji-enable -->\nA 🚀\n", "<!-- remoji-enable -->\nA\n", &[Directive::Enable]),
+            ("<!-- remoji-disable -->\nA\n", "<!-- remoji-disable -->\nA\n", &[Directive::Disable]),
+        ]);
+    }
+
+    #[test]
+    fn comments_in_code_or_unknown_are_ignored() {
+        check(&[
+            ("```\n<!-- remoji-disable -->\n```\nA 🚀\n", "```\n<!-- remoji-disable -->\n```\nA\n", &[]),
+            ("`<!-- remoji-disable-file -->` A 🚀\n", "`<!-- remoji-disable-file -->` A\n", &[]),
+            ("    <!-- remoji-disable-file -->\n\nA 🚀\n", "    <!-- remoji-disable-file -->\n\nA\n", &[]),
+            ("<!-- remoji-disablefoo -->\nA 🚀\n", "<!-- remoji-disablefoo -->\nA\n", &[]),
+            ("<!-- not remoji-disable -->\nA 🚀\n", "<!-- not remoji-disable -->\nA\n", &[]),
+        ]);
+    }
+
+    #[test]
+    fn unused_messages() {
+        let stripper = Stripper::default();
+        let messages: Vec<_> = stripper
+            .check("<!-- remoji-enable -->\n\n<!-- remoji-disable-next-line -->\nA\n")
+            .unused
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "`remoji-enable` without a `remoji-disable` before it",
+                "unused `remoji-disable-next-line`: it suppresses no emojis",
+            ]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Directory traversal that honors `.gitignore`, `.ignore` and `.remojiignore`.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use globset::GlobBuilder;
+use ignore::WalkBuilder;
+
+use crate::config::Loader;
+
+pub const IGNORE_FILE_NAME: &str = ".remojiignore";
+
+#[derive(Debug, Clone, Default)]
+pub struct WalkOptions {
+    /// Descend into hidden files and directories.
+    pub hidden: bool,
+    pub follow_links: bool,
+    pub max_depth: Option<usize>,
+    /// Ignore `.gitignore`, `.ignore` and `.remojiignore` files.
+    pub no_ignore: bool,
+}
+
+/// Files found by a scan, together with the entries that could not be read.
+#[derive(Debug, Default)]
+pub struct Scan {
+    pub files: Vec<PathBuf>,
+    pub errors: Vec<WalkError>,
+}
+
+/// An entry a scan could not read, such as an unreadable directory or a
+/// symbolic link loop.
+#[derive(Debug)]
+pub struct WalkError {
+    pub path: PathBuf,
+    pub error: ignore::Error,
+}
+
+impl WalkError {
+    fn new(error: ignore::Error, root: &Path) -> Self {
+        let path = error_path(&error).unwrap_or(root).to_path_buf();
+        Self { path, error }
+    }
+
+    /// The cause, without the path that is already in `path`.
+    pub fn cause(&self) -> &ignore::Error {
+        let mut error = &self.error;
+        loop {
+            match error {
+                ignore::Error::WithPath { err, .. }
+                | ignore::Error::WithDepth { err, .. }
+                | ignore::Error::WithLineNumber { err, .. } => error = err,
+                _ => return error,
+            }
+        }
+    }
+}
+
+fn error_path(error: &ignore::Error) -> Option<&Path> {
+    match error {
+        ignore::Error::WithPath { path, .. } => Some(path),
+        ignore::Error::Loop { child, .. } => Some(child),
+        ignore::Error::WithDepth { err, .. } | ignore::Error::WithLineNumber { err, .. } => {
+            error_path(err)
+        }
+        _ => None,
+    }
+}
+
+fn builder(root: &Path, options: &WalkOptions) -> WalkBuilder {
+    let respect_ignores = !options.no_ignore;
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(!options.hidden)
+        .follow_links(options.follow_links)
+        .max_depth(options.max_depth)
+        .require_git(false)
+        .parents(respect_ignores)
+        .ignore(respect_ignores)
+        .git_ignore(respect_ignores)
+        .git_global(respect_ignores)
+        .git_exclude(respect_ignores)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .filter_entry(|entry| entry.file_name() != ".git");
+    if respect_ignores {
+        builder.add_custom_ignore_filename(IGNORE_FILE_NAME);
+    }
+    builder
+}
+
+/// The files under `root` that their settings select, sorted by path.
+pub fn files(root: &Path, options: &WalkOptions, loader: &mut Loader) -> Result<Scan> {
+    scan_dir(root, options, loader, true)
+}
+
+/// The files under `root` that their settings don't select, such as images
+/// next to Markdown sources, sorted by path.
+pub fn others(root: &Path, options: &WalkOptions, loader: &mut Loader) -> Result<Scan> {
+    scan_dir(root, options, loader, false)
+}
+
+fn scan_dir(root: &Path, options: &WalkOptions, loader: &mut Loader, selected: bool) -> Result<Scan> {
+    let mut scan = Scan::default();
+    for entry in builder(root, options).build() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(error) => {
+                scan.errors.push(WalkError::new(error, root));
+                continue;
+            }
+        };
+        if entry.file_type().is_some_and(|t| t.is_file())
+            && loader.settings_for(entry.path())?.selects(entry.path()) == selected
+        {
+            scan.files.push(entry.into_path());
+        }
+    }
+
+    Ok(scan)
+}
+
+/// Whether `path` is a glob pattern rather than a path: it does not exist and
+/// contains glob metacharacters.
+pub fn is_glob(path: &Path) -> bool {
+    let text = path.to_string_lossy();
+    text.contains(['*', '?', '[', '{']) && !path.exists()
+}
+
+/// The files matching the glob `pattern` that their settings select, sorted
+/// by path. Only the directory before the first wildcard is scanned.
+pub fn glob(pattern: &Path, options: &WalkOptions, loader: &mut Loader) -> Result<Scan> {
+    let text = pattern.to_string_lossy();
+    let matcher = GlobBuilder::new(&text)
+        .literal_separator(true)
+        .build()
+        .with_context(|| format!("Invalid glob `{}`", text))?
+        .compile_matcher();
+
+    let root: PathBuf = pattern
+        .components()
+        .take_while(|c| !c.as_os_str().to_string_lossy().contains(['*', '?', '[', '{']))
+        .collect();
+    let root = if root.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        root
+    };
+
+    let mut scan = Scan::default();
+    for entry in builder(&root, options).build() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(error) => {
+                scan.errors.push(WalkError::new(error, &root));
+                continue;
+            }
+        };
+        let path = entry.path();
+        // The walk reports `./a.md` for a pattern like `*.md`.
+        let candidate = if pattern.starts_with(".") {
+            path
+        } else {
+            path.strip_prefix(".").unwrap_or(path)
+        };
+        if entry.file_type().is_some_and(|t| t.is_file())
+            && matcher.is_match(candidate)
+            && loader.settings_for(path)?.selects(path)
+        {
+            scan.files.push(entry.into_path());
+        }
+    }
+
+    Ok(scan)
+}
+
+/// Expand command-line inputs into the files to process.
+///
+/// Directories are scanned and glob patterns expanded, keeping what the
+/// settings select; files named in `paths` are always kept, while files from
+/// `listed` (a `--files-from` list) must be selected too. A file reached more
+/// than once is kept only at its first position. Entries that can't be read
+/// while scanning are reported in the scan's errors.
+pub fn expand(
+    paths: &[PathBuf],
+    listed: &[PathBuf],
+    options: &WalkOptions,
+    loader: &mut Loader,
+) -> Result<Scan> {
+    let mut seen = HashSet::new();
+    let mut selected = Vec::new();
+    let mut errors = Vec::new();
+    let mut add = |file: PathBuf| {
+        let key = fs::canonicalize(&file)
+            .or_else(|_| std::path::absolute(&file))
+            .unwrap_or_else(|_| file.clone());
+        if seen.insert(key) {
+            selected.push(file);
+        }
+    };
+
+    for path in paths {
+        let scan = if path.is_dir() {
+            files(path, options, loader)?
+        } else if is_glob(path) {
+            glob(path, options, loader)?
+        } else {
+            add(path.clone());
+            continue;
+        };
+        scan.files.into_iter().for_each(&mut add);
+        errors.extend(scan.errors);
+    }
+    for path in listed {
+        if loader.settings_for(path)?.selects(path) {
+            add(path.clone());
+        }
+    }
+
+    Ok(Scan {
+        files: selected,
+        errors,
+    })
+}